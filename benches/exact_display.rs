@@ -0,0 +1,108 @@
+//! Compares `NearToken::exact()` against the allocating `format!`-based implementation
+//! that `NearToken::exact_amount_display` used before, and counts heap allocations of both.
+//!
+//! Run with `cargo bench --bench exact_display`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use near_token::NearToken;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ONE_NEAR: u128 = 10u128.pow(24);
+const ITERATIONS: u32 = 200_000;
+
+fn legacy_exact_amount_display(tokens: &NearToken) -> String {
+    let yoctonear = tokens.as_yoctonear();
+
+    if yoctonear == 0 {
+        "0 NEAR".to_string()
+    } else if yoctonear <= 1_000 {
+        format!("{} yoctoNEAR", yoctonear)
+    } else if yoctonear % ONE_NEAR == 0 {
+        format!("{} NEAR", yoctonear / ONE_NEAR)
+    } else {
+        format!(
+            "{}.{} NEAR",
+            yoctonear / ONE_NEAR,
+            format!("{:0>24}", yoctonear % ONE_NEAR).trim_end_matches('0')
+        )
+    }
+}
+
+/// Runs `f` for every sample `ITERATIONS` times, returning the average time and allocations per call.
+fn measure(samples: &[NearToken], mut f: impl FnMut(&NearToken)) -> (Duration, f64) {
+    let calls = ITERATIONS * samples.len() as u32;
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        for tokens in samples {
+            f(black_box(tokens));
+        }
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    (elapsed / calls, allocations as f64 / calls as f64)
+}
+
+fn report(name: &str, (per_call, allocations): (Duration, f64)) {
+    println!("{name:<40} {per_call:>10.2?}/call {allocations:>6.2} allocations/call");
+}
+
+fn main() {
+    let groups = [
+        ("zero", vec![NearToken::ZERO]),
+        ("yoctonear", vec![NearToken::from_yoctonear(999)]),
+        ("whole near", vec![NearToken::from_near(1_000_000)]),
+        (
+            "fractional near",
+            vec![NearToken::from_yoctonear(1_234_567_890_123_456_789_000_000)],
+        ),
+        ("max", vec![NearToken::MAX]),
+    ];
+
+    // Reuse one buffer so that only the formatting itself is measured for `exact()`.
+    let mut buffer = String::with_capacity(128);
+    for (name, samples) in &groups {
+        report(
+            &format!("{name}: legacy exact_amount_display"),
+            measure(samples, |tokens| {
+                black_box(legacy_exact_amount_display(tokens));
+            }),
+        );
+        report(
+            &format!("{name}: exact_amount_display"),
+            measure(samples, |tokens| {
+                black_box(tokens.exact_amount_display());
+            }),
+        );
+        report(
+            &format!("{name}: exact() into a reused buffer"),
+            measure(samples, |tokens| {
+                buffer.clear();
+                write!(buffer, "{}", tokens.exact()).unwrap();
+                black_box(&buffer);
+            }),
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use crate::NearToken;
+
+/// A signed change of a `NearToken` balance, e.g. "balance went down by 3 NEAR".
+///
+/// The delta is stored as a sign and a `NearToken` magnitude, so it covers the full
+/// `-u128::MAX..=u128::MAX` yoctoNEAR range and any two `NearToken` values always have a delta between them.
+///
+/// # Examples
+/// ```
+/// use near_token::{NearToken, NearTokenDelta};
+///
+/// let before = NearToken::from_near(5);
+/// let after = NearToken::from_near(2);
+/// let delta = before.delta_to(after);
+/// assert_eq!(delta, NearTokenDelta::negative(NearToken::from_near(3)));
+/// assert_eq!(delta.to_string(), "-3.00 NEAR");
+/// assert_eq!(before.checked_apply(delta), Some(after));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize))]
+#[cfg_attr(feature = "abi", derive(borsh::BorshSchema))]
+pub struct NearTokenDelta {
+    negative: bool,
+    magnitude: NearToken,
+}
+
+impl NearTokenDelta {
+    /// Largest positive delta (+u128::MAX yoctoNEAR)
+    pub const MAX: NearTokenDelta = NearTokenDelta::positive(NearToken::MAX);
+    /// Largest negative delta (-u128::MAX yoctoNEAR)
+    pub const MIN: NearTokenDelta = NearTokenDelta::negative(NearToken::MAX);
+    /// Zero delta
+    pub const ZERO: NearTokenDelta = NearTokenDelta::positive(NearToken::ZERO);
+
+    /// `positive` creates a delta that increases a balance by the given amount.
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenDelta};
+    /// assert!(NearTokenDelta::positive(NearToken::from_near(1)).is_positive())
+    /// ```
+    pub const fn positive(magnitude: NearToken) -> Self {
+        Self {
+            negative: false,
+            magnitude,
+        }
+    }
+
+    /// `negative` creates a delta that decreases a balance by the given amount.
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenDelta};
+    /// assert!(NearTokenDelta::negative(NearToken::from_near(1)).is_negative());
+    /// assert_eq!(NearTokenDelta::negative(NearToken::ZERO), NearTokenDelta::ZERO);
+    /// ```
+    pub const fn negative(magnitude: NearToken) -> Self {
+        Self {
+            negative: !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// `from_yoctonear` creates a delta from a signed number of yocto-near.
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenDelta};
+    /// assert_eq!(NearTokenDelta::from_yoctonear(-5), NearTokenDelta::negative(NearToken::from_yoctonear(5)))
+    /// ```
+    pub const fn from_yoctonear(inner: i128) -> Self {
+        if inner < 0 {
+            Self::negative(NearToken::from_yoctonear(inner.unsigned_abs()))
+        } else {
+            Self::positive(NearToken::from_yoctonear(inner.unsigned_abs()))
+        }
+    }
+
+    /// `magnitude` returns the absolute value of the delta.
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenDelta};
+    /// assert_eq!(NearTokenDelta::from_yoctonear(-5).magnitude(), NearToken::from_yoctonear(5))
+    /// ```
+    pub const fn magnitude(&self) -> NearToken {
+        self.magnitude
+    }
+
+    /// `is_negative` returns true if the delta decreases a balance.
+    /// # Examples
+    /// ```
+    /// use near_token::NearTokenDelta;
+    /// assert_eq!(NearTokenDelta::from_yoctonear(-5).is_negative(), true);
+    /// assert_eq!(NearTokenDelta::ZERO.is_negative(), false);
+    /// ```
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// `is_positive` returns true if the delta increases a balance.
+    /// # Examples
+    /// ```
+    /// use near_token::NearTokenDelta;
+    /// assert_eq!(NearTokenDelta::from_yoctonear(5).is_positive(), true);
+    /// assert_eq!(NearTokenDelta::ZERO.is_positive(), false);
+    /// ```
+    pub const fn is_positive(&self) -> bool {
+        !self.negative && !self.magnitude.is_zero()
+    }
+
+    /// `is_zero` returns true if the delta does not change a balance.
+    /// # Examples
+    /// ```
+    /// use near_token::NearTokenDelta;
+    /// assert_eq!(NearTokenDelta::from_yoctonear(0).is_zero(), true)
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// `as_yoctonear` returns the signed number of yocto-near, or None if it does not fit into `i128`.
+    /// # Examples
+    /// ```
+    /// use near_token::NearTokenDelta;
+    /// assert_eq!(NearTokenDelta::from_yoctonear(-5).as_yoctonear(), Some(-5));
+    /// assert_eq!(NearTokenDelta::MAX.as_yoctonear(), None);
+    /// ```
+    pub const fn as_yoctonear(&self) -> Option<i128> {
+        let magnitude = self.magnitude.as_yoctonear();
+        if self.negative {
+            if magnitude <= i128::MIN.unsigned_abs() {
+                Some((magnitude as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else if magnitude <= i128::MAX as u128 {
+            Some(magnitude as i128)
+        } else {
+            None
+        }
+    }
+
+    /// Checked delta addition. Computes self + rhs, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearTokenDelta;
+    /// assert_eq!(NearTokenDelta::from_yoctonear(5).checked_add(NearTokenDelta::from_yoctonear(-7)), Some(NearTokenDelta::from_yoctonear(-2)));
+    /// assert_eq!(NearTokenDelta::MAX.checked_add(NearTokenDelta::from_yoctonear(1)), None);
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.negative == rhs.negative {
+            match self.magnitude.checked_add(rhs.magnitude) {
+                Some(magnitude) => Some(Self {
+                    negative: self.negative,
+                    magnitude,
+                }),
+                None => None,
+            }
+        } else if self.magnitude.as_yoctonear() >= rhs.magnitude.as_yoctonear() {
+            let magnitude = self.magnitude.saturating_sub(rhs.magnitude);
+            Some(if self.negative {
+                Self::negative(magnitude)
+            } else {
+                Self::positive(magnitude)
+            })
+        } else {
+            let magnitude = rhs.magnitude.saturating_sub(self.magnitude);
+            Some(if rhs.negative {
+                Self::negative(magnitude)
+            } else {
+                Self::positive(magnitude)
+            })
+        }
+    }
+
+    /// Checked delta subtraction. Computes self - rhs, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearTokenDelta;
+    /// assert_eq!(NearTokenDelta::from_yoctonear(5).checked_sub(NearTokenDelta::from_yoctonear(7)), Some(NearTokenDelta::from_yoctonear(-2)));
+    /// assert_eq!(NearTokenDelta::MIN.checked_sub(NearTokenDelta::from_yoctonear(1)), None);
+    /// ```
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.const_neg())
+    }
+
+    const fn const_neg(self) -> Self {
+        if self.negative {
+            Self::positive(self.magnitude)
+        } else {
+            Self::negative(self.magnitude)
+        }
+    }
+}
+
+impl NearToken {
+    /// Computes the signed change needed to turn `self` into `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenDelta};
+    /// assert_eq!(NearToken::from_near(5).delta_to(NearToken::from_near(2)), NearTokenDelta::negative(NearToken::from_near(3)));
+    /// assert_eq!(NearToken::from_near(2).delta_to(NearToken::from_near(5)), NearTokenDelta::positive(NearToken::from_near(3)));
+    /// ```
+    pub const fn delta_to(self, other: NearToken) -> NearTokenDelta {
+        if other.as_yoctonear() >= self.as_yoctonear() {
+            NearTokenDelta::positive(other.saturating_sub(self))
+        } else {
+            NearTokenDelta::negative(self.saturating_sub(other))
+        }
+    }
+
+    /// Checked delta application. Computes self + delta, returning None if the result does not fit into `NearToken`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenDelta};
+    /// assert_eq!(NearToken::from_near(5).checked_apply(NearTokenDelta::negative(NearToken::from_near(3))), Some(NearToken::from_near(2)));
+    /// assert_eq!(NearToken::from_near(2).checked_apply(NearTokenDelta::negative(NearToken::from_near(3))), None);
+    /// ```
+    pub const fn checked_apply(self, delta: NearTokenDelta) -> Option<NearToken> {
+        if delta.negative {
+            self.checked_sub(delta.magnitude)
+        } else {
+            self.checked_add(delta.magnitude)
+        }
+    }
+}
+
+impl From<NearToken> for NearTokenDelta {
+    fn from(tokens: NearToken) -> Self {
+        NearTokenDelta::positive(tokens)
+    }
+}
+
+impl core::ops::Neg for NearTokenDelta {
+    type Output = NearTokenDelta;
+
+    fn neg(self) -> Self::Output {
+        self.const_neg()
+    }
+}
+
+impl PartialOrd for NearTokenDelta {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NearTokenDelta {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => core::cmp::Ordering::Greater,
+            (true, false) => core::cmp::Ordering::Less,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NearToken, NearTokenDelta};
+
+    #[test]
+    fn delta_to() {
+        let small = NearToken::from_yoctonear(1);
+        assert_eq!(
+            NearToken::MAX.delta_to(NearToken::ZERO),
+            NearTokenDelta::MIN
+        );
+        assert_eq!(
+            NearToken::ZERO.delta_to(NearToken::MAX),
+            NearTokenDelta::MAX
+        );
+        assert_eq!(small.delta_to(small), NearTokenDelta::ZERO);
+        assert!(!small.delta_to(small).is_negative());
+    }
+
+    #[test]
+    fn checked_apply() {
+        for (from, to) in [(0, 0), (0, u128::MAX), (u128::MAX, 0), (10, 3), (3, 10)] {
+            let from = NearToken::from_yoctonear(from);
+            let to = NearToken::from_yoctonear(to);
+            assert_eq!(from.checked_apply(from.delta_to(to)), Some(to));
+        }
+        assert_eq!(
+            NearToken::MAX.checked_apply(NearTokenDelta::from_yoctonear(1)),
+            None
+        );
+        assert_eq!(
+            NearToken::ZERO.checked_apply(NearTokenDelta::from_yoctonear(-1)),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_add_sub() {
+        let plus_five = NearTokenDelta::from_yoctonear(5);
+        let minus_five = NearTokenDelta::from_yoctonear(-5);
+        assert_eq!(
+            plus_five.checked_add(minus_five),
+            Some(NearTokenDelta::ZERO)
+        );
+        assert!(!plus_five.checked_add(minus_five).unwrap().is_negative());
+        assert_eq!(
+            minus_five.checked_add(minus_five),
+            Some(NearTokenDelta::from_yoctonear(-10))
+        );
+        assert_eq!(
+            minus_five.checked_sub(plus_five),
+            Some(NearTokenDelta::from_yoctonear(-10))
+        );
+        assert_eq!(
+            NearTokenDelta::MIN.checked_add(NearTokenDelta::MAX),
+            Some(NearTokenDelta::ZERO)
+        );
+        assert_eq!(NearTokenDelta::MIN.checked_add(minus_five), None);
+        assert_eq!(NearTokenDelta::MAX.checked_sub(minus_five), None);
+    }
+
+    #[test]
+    fn as_yoctonear() {
+        for value in [0, 1, -1, i128::MAX, i128::MIN] {
+            assert_eq!(
+                NearTokenDelta::from_yoctonear(value).as_yoctonear(),
+                Some(value)
+            );
+        }
+        assert_eq!(NearTokenDelta::MIN.as_yoctonear(), None);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut deltas = [
+            NearTokenDelta::MAX,
+            NearTokenDelta::from_yoctonear(-1),
+            NearTokenDelta::ZERO,
+            NearTokenDelta::MIN,
+            NearTokenDelta::from_yoctonear(-2),
+            NearTokenDelta::from_yoctonear(1),
+        ];
+        deltas.sort();
+        assert_eq!(
+            deltas,
+            [
+                NearTokenDelta::MIN,
+                NearTokenDelta::from_yoctonear(-2),
+                NearTokenDelta::from_yoctonear(-1),
+                NearTokenDelta::ZERO,
+                NearTokenDelta::from_yoctonear(1),
+                NearTokenDelta::MAX,
+            ]
+        );
+        assert_eq!(-NearTokenDelta::MAX, NearTokenDelta::MIN);
+        assert_eq!(-NearTokenDelta::ZERO, NearTokenDelta::ZERO);
+    }
+}
@@ -1,11 +1,18 @@
+/// Error returned by the `FromStr` implementations of `NearToken` and `NearTokenDelta`,
+/// carrying the offending input.
+///
+/// Requires the **alloc** feature; without it, use [`NearToken::parse_const`](crate::NearToken::parse_const),
+/// which returns [`ParseError`].
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NearTokenError {
     InvalidTokensAmount(crate::utils::DecimalNumberParsingError),
-    InvalidTokenUnit(String),
+    InvalidTokenUnit(alloc::string::String),
 }
 
-impl std::fmt::Display for NearTokenError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for NearTokenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             NearTokenError::InvalidTokensAmount(err) => write!(f, "invalid tokens amount: {}", err),
             NearTokenError::InvalidTokenUnit(unit) => write!(f, "invalid token unit: {}", unit),
@@ -13,6 +20,7 @@ impl std::fmt::Display for NearTokenError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for NearTokenError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -22,11 +30,212 @@ impl std::error::Error for NearTokenError {
     }
 }
 
+/// Error returned by [`NearToken::parse_const`](crate::NearToken::parse_const).
+///
+/// Unlike `NearTokenError` it does not carry the offending input, so it is `Copy` and can be used in `const` context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input has no token unit, e.g. `"1.25"`.
+    MissingUnit,
+    /// The token unit is not one of the supported ones, e.g. `"1.25 UAH"`.
+    InvalidUnit,
+    /// The amount is not a decimal number, e.g. `"1.2.5 NEAR"` or `"-1 NEAR"`.
+    InvalidNumber,
+    /// The amount does not fit into `NearToken`.
+    LongWhole,
+    /// The amount is more precise than 1 yoctoNEAR, e.g. `"0.5 yoctoNEAR"` or `"5e-1 yoctoNEAR"`.
+    LongFractional,
+    /// The amount in exponent notation does not fit into `NearToken`, e.g. `"1e40 NEAR"`.
+    ExponentOverflow,
+    /// Digit group separators allowed by [`ParseOptions`](crate::ParseOptions) are misplaced, e.g. `"1,00 NEAR"`.
+    InvalidDigitGrouping,
+}
+
+impl ParseError {
+    /// `as_str` returns the description of the error, and unlike `Display` it can be used in `const` context.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, ParseError};
+    ///
+    /// const ERROR: ParseError = match NearToken::parse_const("1.25") {
+    ///     Ok(_) => panic!("unreachable"),
+    ///     Err(err) => err,
+    /// };
+    /// assert_eq!(ERROR.as_str(), "missing token unit");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ParseError::MissingUnit => "missing token unit",
+            ParseError::InvalidUnit => "invalid token unit",
+            ParseError::InvalidNumber => "invalid number",
+            ParseError::LongWhole => "too long whole part",
+            ParseError::LongFractional => "too long fractional part",
+            ParseError::ExponentOverflow => "too large exponent",
+            ParseError::InvalidDigitGrouping => "invalid digit grouping",
+        }
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Kind of [`ParseDiagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input has no token unit, e.g. `"1.25"`.
+    MissingUnit,
+    /// The token unit is not one of the supported ones, e.g. `"1.25 NAER"`.
+    UnknownUnit,
+    /// The amount has no digits before the decimal separator, e.g. `".5 NEAR"`.
+    EmptyInteger,
+    /// The amount is not a decimal number, e.g. `"1.2.5 NEAR"` or `"-1 NEAR"`.
+    InvalidNumber,
+    /// Digit group separators allowed by [`ParseOptions`](crate::ParseOptions) are misplaced, e.g. `"1,00 NEAR"`.
+    InvalidDigitGrouping,
+    /// The amount is more precise than 1 yoctoNEAR, e.g. `"0.5 yoctoNEAR"`.
+    TooManyFractionalDigits {
+        /// Maximum number of fractional digits in the unit of the amount.
+        max: u32,
+    },
+    /// The amount does not fit into `NearToken`, e.g. `"1e40 NEAR"`.
+    Overflow,
+}
+
+impl core::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseErrorKind::MissingUnit => f.write_str("missing token unit"),
+            ParseErrorKind::UnknownUnit => f.write_str("invalid token unit"),
+            ParseErrorKind::EmptyInteger => f.write_str("missing integer part"),
+            ParseErrorKind::InvalidNumber => f.write_str("invalid number"),
+            ParseErrorKind::InvalidDigitGrouping => f.write_str("invalid digit grouping"),
+            ParseErrorKind::TooManyFractionalDigits { max } => {
+                write!(
+                    f,
+                    "too long fractional part, at most {} digits are allowed",
+                    max
+                )
+            }
+            ParseErrorKind::Overflow => f.write_str("too large amount"),
+        }
+    }
+}
+
+/// Error returned by [`NearToken::parse_with_details`](crate::NearToken::parse_with_details).
+///
+/// Besides the kind of the error, it points at the offending part of the input
+/// and suggests the intended unit when the unit looks like a typo.
+///
+/// # Examples
+/// ```
+/// use near_token::{NearToken, NearUnit, ParseErrorKind, ParseOptions};
+///
+/// let input = "1.5 NAER";
+/// let err = NearToken::parse_with_details(input, &ParseOptions::new()).unwrap_err();
+/// assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+/// assert_eq!(err.suggestion(), Some(NearUnit::Near));
+/// assert_eq!(err.to_string(), "invalid token unit, did you mean `NEAR`?");
+///
+/// let span = err.span();
+/// let caret = format!("{}{}", " ".repeat(span.start), "^".repeat(span.len()));
+/// assert_eq!(format!("{}\n{}", input, caret), "1.5 NAER\n    ^^^^");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseDiagnostic {
+    kind: ParseErrorKind,
+    start: usize,
+    end: usize,
+    suggestion: Option<crate::NearUnit>,
+}
+
+impl ParseDiagnostic {
+    pub(crate) const fn new(kind: ParseErrorKind, start: usize, end: usize) -> Self {
+        Self {
+            kind,
+            start,
+            end,
+            suggestion: None,
+        }
+    }
+
+    pub(crate) const fn with_suggestion(mut self, suggestion: Option<crate::NearUnit>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    /// Returns the kind of the error.
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the offending part of the input.
+    ///
+    /// The range is empty when something is missing, e.g. it points right after the amount for a missing unit.
+    pub const fn span(&self) -> core::ops::Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the unit that was probably meant by an unknown unit.
+    pub const fn suggestion(&self) -> Option<crate::NearUnit> {
+        self.suggestion
+    }
+}
+
+impl core::fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(unit) = self.suggestion {
+            write!(f, ", did you mean `{}`?", unit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDiagnostic {}
+
+/// Error returned when a `NearToken` amount cannot be split between recipients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SplitError {
+    /// There are no recipients, or all of them have zero weight.
+    ZeroTotalWeight,
+    /// The sum of all the weights does not fit into `u128`.
+    TotalWeightOverflow,
+    /// The amount was asked to be split into zero parts.
+    ZeroParts,
+}
+
+impl core::fmt::Display for SplitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SplitError::ZeroTotalWeight => write!(f, "total weight of recipients is zero"),
+            SplitError::TotalWeightOverflow => {
+                write!(f, "total weight of recipients does not fit into u128")
+            }
+            SplitError::ZeroParts => write!(f, "number of parts is zero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SplitError {}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_near_token_error_display() {
         assert_eq!(
             format!(
@@ -62,4 +271,41 @@ mod test {
             "invalid token unit: abc"
         );
     }
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(ParseError::MissingUnit.to_string(), "missing token unit");
+        assert_eq!(
+            ParseError::LongFractional.to_string(),
+            "too long fractional part"
+        );
+    }
+
+    #[test]
+    fn test_parse_diagnostic_display() {
+        assert_eq!(
+            ParseDiagnostic::new(ParseErrorKind::TooManyFractionalDigits { max: 3 }, 6, 7)
+                .to_string(),
+            "too long fractional part, at most 3 digits are allowed"
+        );
+        assert_eq!(
+            ParseDiagnostic::new(ParseErrorKind::UnknownUnit, 2, 11)
+                .with_suggestion(Some(crate::NearUnit::MilliNear))
+                .to_string(),
+            "invalid token unit, did you mean `milliNEAR`?"
+        );
+    }
+
+    #[test]
+    fn test_split_error_display() {
+        assert_eq!(
+            SplitError::ZeroTotalWeight.to_string(),
+            "total weight of recipients is zero"
+        );
+        assert_eq!(
+            SplitError::TotalWeightOverflow.to_string(),
+            "total weight of recipients does not fit into u128"
+        );
+        assert_eq!(SplitError::ZeroParts.to_string(), "number of parts is zero");
+    }
 }
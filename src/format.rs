@@ -0,0 +1,723 @@
+use core::fmt::Write;
+
+use crate::utils::StackBuffer;
+use crate::{Locale, NearToken, NearTokenDelta, NearUnit, Rounding, UnitPlacement};
+
+/// Configurable renderer of `NearToken` amounts.
+///
+/// The builder describes how an amount should look like (unit, number of fractional digits, rounding,
+/// separators), and [`NearTokenFormatter::format`] returns a wrapper implementing `Display`,
+/// so formatting never allocates on its own.
+///
+/// The number of fractional digits is always between `min_fractional_digits` and `max_fractional_digits`:
+/// the amount is rounded to `max_fractional_digits` with the selected rounding mode,
+/// and then trailing zeros are trimmed down to `min_fractional_digits`.
+///
+/// `NearToken` Display implementation and [`NearToken::exact_amount_display`] are presets of this builder,
+/// see [`NearTokenFormatter::compact`] and [`NearTokenFormatter::exact`].
+///
+/// # Examples
+/// ```
+/// use near_token::{NearToken, NearTokenFormatter, NearUnit, Rounding};
+///
+/// let tokens = NearToken::from_yoctonear(1_234_567_890_000_000_000_000_000_000);
+///
+/// let wallet = NearTokenFormatter::compact();
+/// assert_eq!(wallet.format(tokens).to_string(), "1234.57 NEAR");
+///
+/// let explorer = NearTokenFormatter::new().min_fractional_digits(24);
+/// assert_eq!(explorer.format(tokens).to_string(), "1234.567890000000000000000000 NEAR");
+///
+/// let tax_report = NearTokenFormatter::new()
+///     .min_fractional_digits(6)
+///     .max_fractional_digits(6)
+///     .rounding(Rounding::HalfEven)
+///     .thousands_separator(Some(','));
+/// assert_eq!(tax_report.format(tokens).to_string(), "1,234.567890 NEAR");
+///
+/// let csv = NearTokenFormatter::new()
+///     .unit(NearUnit::MilliNear)
+///     .decimal_separator(',')
+///     .show_unit(false);
+/// assert_eq!(csv.format(tokens).to_string(), "1234567,89");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NearTokenFormatter {
+    unit: NearUnit,
+    min_fractional_digits: usize,
+    max_fractional_digits: usize,
+    fractional_digits_below_one: Option<usize>,
+    rounding: Rounding,
+    thousands_separator: Option<char>,
+    primary_group: u8,
+    secondary_group: u8,
+    decimal_separator: char,
+    show_unit: bool,
+    unit_placement: UnitPlacement,
+    compact_zero: bool,
+    show_less_than: bool,
+    yoctonear_threshold: Option<u128>,
+}
+
+impl NearTokenFormatter {
+    /// Creates a formatter that displays the exact amount in NEAR with trailing zeros trimmed,
+    /// e.g. `1.5 NEAR`, `1234 NEAR` or `0.000000000000000000000001 NEAR`.
+    pub const fn new() -> Self {
+        Self {
+            unit: NearUnit::Near,
+            min_fractional_digits: 0,
+            max_fractional_digits: usize::MAX,
+            fractional_digits_below_one: None,
+            rounding: Rounding::Down,
+            thousands_separator: None,
+            primary_group: 3,
+            secondary_group: 3,
+            decimal_separator: '.',
+            show_unit: true,
+            unit_placement: UnitPlacement::Suffix,
+            compact_zero: false,
+            show_less_than: false,
+            yoctonear_threshold: None,
+        }
+    }
+
+    /// Preset used by [`NearToken::exact_amount_display`] and the alternate `{:#}` Display of `NearToken`:
+    /// the exact amount in NEAR, or in yoctoNEAR for amounts up to 1000 yoctoNEAR.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenFormatter};
+    /// let formatter = NearTokenFormatter::exact();
+    /// assert_eq!(formatter.format(NearToken::from_millinear(1500)).to_string(), "1.5 NEAR");
+    /// assert_eq!(formatter.format(NearToken::from_yoctonear(500)).to_string(), "500 yoctoNEAR");
+    /// assert_eq!(formatter.format(NearToken::ZERO).to_string(), "0 NEAR");
+    /// ```
+    pub const fn exact() -> Self {
+        Self::new().yoctonear_threshold(Some(1_000))
+    }
+
+    /// Preset used by `NearToken` Display implementation: the amount in NEAR rounded up
+    /// to 2 fractional digits, or to 3 fractional digits for amounts below 1 NEAR.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenFormatter};
+    /// let formatter = NearTokenFormatter::compact();
+    /// assert_eq!(formatter.format(NearToken::from_millinear(1234)).to_string(), "1.24 NEAR");
+    /// assert_eq!(formatter.format(NearToken::from_millinear(200)).to_string(), "0.200 NEAR");
+    /// assert_eq!(formatter.format(NearToken::from_yoctonear(1)).to_string(), "<0.001 NEAR");
+    /// assert_eq!(formatter.format(NearToken::ZERO).to_string(), "0 NEAR");
+    /// ```
+    pub const fn compact() -> Self {
+        Self::new()
+            .min_fractional_digits(2)
+            .max_fractional_digits(2)
+            .fractional_digits_below_one(Some(3))
+            .rounding(Rounding::Up)
+            .compact_zero(true)
+            .show_less_than(true)
+    }
+
+    /// Sets the unit the amount is displayed in (NEAR by default).
+    pub const fn unit(mut self, unit: NearUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Sets the minimum number of fractional digits, trailing zeros are trimmed down to it (0 by default).
+    pub const fn min_fractional_digits(mut self, digits: usize) -> Self {
+        self.min_fractional_digits = digits;
+        self
+    }
+
+    /// Sets the maximum number of fractional digits, the amount is rounded to it
+    /// (unlimited by default, i.e. the exact amount is displayed).
+    pub const fn max_fractional_digits(mut self, digits: usize) -> Self {
+        self.max_fractional_digits = digits;
+        self
+    }
+
+    /// Sets the exact number of fractional digits used instead of `min_fractional_digits` and `max_fractional_digits`
+    /// for amounts that stay below one unit after rounding (disabled by default).
+    pub const fn fractional_digits_below_one(mut self, digits: Option<usize>) -> Self {
+        self.fractional_digits_below_one = digits;
+        self
+    }
+
+    /// Sets the rounding mode applied when the amount has more fractional digits than displayed
+    /// (`Rounding::Down` by default).
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets the separator inserted between groups of three digits in the integer part (none by default).
+    pub const fn thousands_separator(mut self, separator: Option<char>) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+
+    /// Sets the sizes of digit groups separated by `thousands_separator`: the group nearest to the decimal separator
+    /// has `primary` digits, and the other ones have `secondary` digits (3 and 3 by default).
+    ///
+    /// # Panics
+    /// Panics if any of the sizes is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenFormatter};
+    /// let formatter = NearTokenFormatter::new().thousands_separator(Some(',')).grouping(3, 2);
+    /// assert_eq!(formatter.format(NearToken::from_near(12_345_678)).to_string(), "1,23,45,678 NEAR");
+    /// ```
+    pub const fn grouping(mut self, primary: u8, secondary: u8) -> Self {
+        assert!(
+            primary != 0 && secondary != 0,
+            "digit group sizes must not be zero"
+        );
+        self.primary_group = primary;
+        self.secondary_group = secondary;
+        self
+    }
+
+    /// Sets the separator between the integer and the fractional parts (`.` by default).
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets whether the unit name is displayed after the number (enabled by default).
+    pub const fn show_unit(mut self, show_unit: bool) -> Self {
+        self.show_unit = show_unit;
+        self
+    }
+
+    /// Sets whether the unit name is displayed before or after the number (`UnitPlacement::Suffix` by default).
+    pub const fn unit_placement(mut self, placement: UnitPlacement) -> Self {
+        self.unit_placement = placement;
+        self
+    }
+
+    /// Applies the decimal separator, digit grouping and unit placement of `locale`,
+    /// keeping the other settings.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{Locale, NearToken, NearTokenFormatter};
+    /// let formatter = NearTokenFormatter::new().locale(&Locale::DE_DE);
+    /// assert_eq!(formatter.format(NearToken::from_millinear(1_234_500)).to_string(), "1.234,5 NEAR");
+    /// ```
+    pub const fn locale(self, locale: &Locale) -> Self {
+        self.decimal_separator(locale.decimal_separator.as_char())
+            .thousands_separator(locale.group_separator)
+            .grouping(locale.primary_group, locale.secondary_group)
+            .unit_placement(locale.unit_placement)
+    }
+
+    /// Sets whether zero is displayed as `0` regardless of `min_fractional_digits` (disabled by default).
+    pub const fn compact_zero(mut self, compact_zero: bool) -> Self {
+        self.compact_zero = compact_zero;
+        self
+    }
+
+    /// Sets whether non-zero amounts below the smallest displayed fraction are displayed as e.g. `<0.001`
+    /// instead of being rounded (disabled by default).
+    pub const fn show_less_than(mut self, show_less_than: bool) -> Self {
+        self.show_less_than = show_less_than;
+        self
+    }
+
+    /// Sets the amount of yoctoNEAR up to which non-zero amounts are displayed in yoctoNEAR
+    /// regardless of the selected unit (disabled by default).
+    pub const fn yoctonear_threshold(mut self, threshold: Option<u128>) -> Self {
+        self.yoctonear_threshold = threshold;
+        self
+    }
+
+    /// Returns a wrapper that displays `tokens` with this formatter.
+    ///
+    /// The wrapper respects width, fill, alignment and `+` flag of the outer format string.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenFormatter};
+    /// let formatter = NearTokenFormatter::compact();
+    /// assert_eq!(format!("{:>10}", formatter.format(NearToken::from_near(1))), " 1.00 NEAR");
+    /// ```
+    pub const fn format(&self, tokens: NearToken) -> FormattedNearToken {
+        FormattedNearToken {
+            formatter: *self,
+            tokens,
+            sign: None,
+        }
+    }
+
+    /// Returns a wrapper that displays `delta` with this formatter, prefixed with its sign.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearTokenDelta, NearTokenFormatter};
+    /// let formatter = NearTokenFormatter::new();
+    /// let delta = NearTokenDelta::negative(NearToken::from_millinear(1500));
+    /// assert_eq!(formatter.format_delta(delta).to_string(), "-1.5 NEAR");
+    /// ```
+    pub const fn format_delta(&self, delta: NearTokenDelta) -> FormattedNearToken {
+        FormattedNearToken {
+            formatter: *self,
+            tokens: delta.magnitude(),
+            sign: if delta.is_negative() {
+                Some('-')
+            } else if delta.is_positive() {
+                Some('+')
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Selects the preset matching the flags of a `Display` format string, see `NearToken` Display implementation.
+    pub(crate) fn from_format_flags(f: &core::fmt::Formatter<'_>) -> Self {
+        if f.alternate() {
+            Self::exact()
+        } else if let Some(precision) = f.precision() {
+            Self::compact()
+                .min_fractional_digits(precision)
+                .max_fractional_digits(precision)
+                .fractional_digits_below_one(None)
+                .compact_zero(false)
+        } else {
+            Self::compact()
+        }
+    }
+
+    fn write_amount(&self, w: &mut impl Write, tokens: NearToken) -> core::fmt::Result {
+        let yoctonear = tokens.as_yoctonear();
+        let unit = match self.yoctonear_threshold {
+            Some(threshold) if yoctonear != 0 && yoctonear <= threshold => NearUnit::YoctoNear,
+            _ => self.unit,
+        };
+
+        let show_unit_before = self.show_unit && self.unit_placement == UnitPlacement::Prefix;
+        if show_unit_before {
+            write!(w, "{} ", unit.name())?;
+        }
+
+        if yoctonear == 0 && self.compact_zero {
+            w.write_char('0')?;
+        } else {
+            let decimals = unit.decimals() as usize;
+            let mut digits = self.max_fractional_digits.min(decimals);
+            let mut min_digits = self.min_fractional_digits.min(digits);
+            if let Some(digits_below_one) = self.fractional_digits_below_one {
+                let digits_below_one = digits_below_one.min(decimals);
+                let rounded = self
+                    .rounding
+                    .divide(yoctonear, unit.multiplier() / pow10(digits_below_one))
+                    .ok_or(core::fmt::Error)?;
+                if rounded < pow10(digits_below_one) {
+                    digits = digits_below_one;
+                    min_digits = digits_below_one;
+                }
+            }
+
+            let step = unit.multiplier() / pow10(digits);
+            if self.show_less_than && yoctonear != 0 && yoctonear < step {
+                w.write_char('<')?;
+                self.write_number(w, 1, digits, digits)?;
+            } else {
+                let rounded = self
+                    .rounding
+                    .divide(yoctonear, step)
+                    .ok_or(core::fmt::Error)?;
+                self.write_number(w, rounded, digits, min_digits)?;
+            }
+        }
+
+        if self.show_unit && !show_unit_before {
+            write!(w, " {}", unit.name())?;
+        }
+        Ok(())
+    }
+
+    /// Writes `value / 10^digits`, trimming trailing zeros of the fractional part down to `min_digits`.
+    fn write_number(
+        &self,
+        w: &mut impl Write,
+        value: u128,
+        mut digits: usize,
+        min_digits: usize,
+    ) -> core::fmt::Result {
+        let scale = pow10(digits);
+        let mut fractional = value % scale;
+        if fractional == 0 {
+            // Skip the per-digit trimming below, u128 divisions are expensive.
+            digits = min_digits;
+        }
+        while digits > min_digits && fractional % 10 == 0 {
+            fractional /= 10;
+            digits -= 1;
+        }
+
+        self.write_integer(w, value / scale)?;
+        if digits > 0 {
+            w.write_char(self.decimal_separator)?;
+            write!(w, "{:0digits$}", fractional, digits = digits)?;
+        }
+        Ok(())
+    }
+
+    fn write_integer(&self, w: &mut impl Write, mut value: u128) -> core::fmt::Result {
+        let separator = match self.thousands_separator {
+            Some(separator) => separator,
+            None => return write!(w, "{}", value),
+        };
+        // u128::MAX has 39 decimal digits
+        let mut digits = [0u8; 39];
+        let mut len = 0;
+        loop {
+            digits[len] = b'0' + (value % 10) as u8;
+            len += 1;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        let (primary, secondary) = (
+            usize::from(self.primary_group),
+            usize::from(self.secondary_group),
+        );
+        for position in (0..len).rev() {
+            w.write_char(char::from(digits[position]))?;
+            // `position` is the number of digits that follow the current one.
+            if position >= primary && (position - primary) % secondary == 0 {
+                w.write_char(separator)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for NearTokenFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `NearToken` amount prepared for display with a [`NearTokenFormatter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormattedNearToken {
+    formatter: NearTokenFormatter,
+    tokens: NearToken,
+    sign: Option<char>,
+}
+
+impl core::fmt::Display for FormattedNearToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // `NearToken::exact` is used to dump lots of balances, so it skips the general formatting.
+        if self.sign.is_none()
+            && f.width().is_none()
+            && !f.sign_plus()
+            && self.formatter == NearTokenFormatter::exact()
+        {
+            return write_exact(f, self.tokens);
+        }
+        let mut buf = StackBuffer::<FORMATTED_BUFFER_LEN>::new();
+        match self.sign {
+            Some(sign) => buf.write_char(sign)?,
+            None if f.sign_plus() => buf.write_char('+')?,
+            None => {}
+        }
+        self.formatter.write_amount(&mut buf, self.tokens)?;
+        pad(f, buf.as_str())
+    }
+}
+
+/// Writes `tokens` as [`NearTokenFormatter::exact`] does, but with u64 arithmetic where possible,
+/// since u128 divisions and the general formatting are comparatively slow.
+pub(crate) fn write_exact(w: &mut impl Write, tokens: NearToken) -> core::fmt::Result {
+    let yoctonear = tokens.as_yoctonear();
+    if yoctonear == 0 {
+        return w.write_str("0 NEAR");
+    }
+    if yoctonear <= 1_000 {
+        return write!(w, "{} yoctoNEAR", yoctonear as u16);
+    }
+    // u128::MAX yoctoNEAR is less than 2^49 NEAR
+    let whole = (yoctonear / crate::ONE_NEAR) as u64;
+    let fractional = yoctonear % crate::ONE_NEAR;
+    if fractional == 0 {
+        return write!(w, "{} NEAR", whole);
+    }
+    // The 24 fractional digits are split into two halves that fit into u64.
+    const HALF: u128 = 10u128.pow(12);
+    let halves = [(fractional / HALF) as u64, (fractional % HALF) as u64];
+    let mut digits = [b'0'; 24];
+    for (chunk, mut value) in digits.chunks_mut(12).zip(halves) {
+        for digit in chunk.iter_mut().rev() {
+            *digit = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+    }
+    let len = digits
+        .iter()
+        .rposition(|&digit| digit != b'0')
+        .map_or(0, |i| i + 1);
+    let digits = core::str::from_utf8(&digits[..len]).map_err(|_| core::fmt::Error)?;
+    write!(w, "{}.{} NEAR", whole, digits)
+}
+
+/// Large enough for the worst case: a sign, `<`, at most 40 digits (39 digits of `u128::MAX` or a leading zero
+/// with 24 fractional digits), a 4-byte separator after every digit of the integer part but the last one,
+/// a 4-byte decimal separator and the unit with a space.
+const FORMATTED_BUFFER_LEN: usize = 4 + 1 + 40 + 38 * 4 + 4 + 1 + 9;
+
+const fn pow10(exponent: usize) -> u128 {
+    10u128.pow(exponent as u32)
+}
+
+/// Writes `s` honoring the width, fill and alignment of the formatter, but not its precision.
+fn pad(f: &mut core::fmt::Formatter<'_>, s: &str) -> core::fmt::Result {
+    let width = match f.width() {
+        Some(width) => width,
+        None => return f.write_str(s),
+    };
+    let len = s.chars().count();
+    if len >= width {
+        return f.write_str(s);
+    }
+    let padding = width - len;
+    let (before, after) = match f.align() {
+        None | Some(core::fmt::Alignment::Left) => (0, padding),
+        Some(core::fmt::Alignment::Right) => (padding, 0),
+        Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NearToken, NearTokenDelta, NearTokenFormatter, NearUnit, Rounding};
+
+    #[test]
+    fn fractional_digits() {
+        let tokens = NearToken::from_yoctonear(1_500_000_000_000_000_000_000_000);
+        for (formatter, expected) in [
+            (NearTokenFormatter::new(), "1.5 NEAR"),
+            (
+                NearTokenFormatter::new().min_fractional_digits(3),
+                "1.500 NEAR",
+            ),
+            (NearTokenFormatter::new().max_fractional_digits(0), "1 NEAR"),
+            (
+                NearTokenFormatter::new()
+                    .max_fractional_digits(0)
+                    .rounding(Rounding::HalfUp),
+                "2 NEAR",
+            ),
+            (
+                NearTokenFormatter::new()
+                    .max_fractional_digits(0)
+                    .rounding(Rounding::HalfEven),
+                "2 NEAR",
+            ),
+            (
+                NearTokenFormatter::new().min_fractional_digits(30),
+                "1.500000000000000000000000 NEAR",
+            ),
+            (
+                NearTokenFormatter::new()
+                    .min_fractional_digits(4)
+                    .max_fractional_digits(2),
+                "1.50 NEAR",
+            ),
+        ] {
+            assert_eq!(formatter.format(tokens).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn units() {
+        let tokens = NearToken::from_yoctonear(1_234_567_000_000_000_000_000);
+        for (unit, expected) in [
+            (NearUnit::Near, "0.001234567 NEAR"),
+            (NearUnit::MilliNear, "1.234567 milliNEAR"),
+            (NearUnit::MicroNear, "1234.567 microNEAR"),
+            (NearUnit::YoctoNear, "1234567000000000000000 yoctoNEAR"),
+        ] {
+            let formatter = NearTokenFormatter::new().unit(unit);
+            assert_eq!(formatter.format(tokens).to_string(), expected);
+        }
+        assert_eq!(
+            NearTokenFormatter::new()
+                .unit(NearUnit::YoctoNear)
+                .min_fractional_digits(5)
+                .format(tokens)
+                .to_string(),
+            "1234567000000000000000 yoctoNEAR"
+        );
+    }
+
+    #[test]
+    fn separators() {
+        let formatter = NearTokenFormatter::new()
+            .thousands_separator(Some('.'))
+            .decimal_separator(',')
+            .show_unit(false);
+        for (tokens, expected) in [
+            (NearToken::ZERO, "0"),
+            (NearToken::from_near(999), "999"),
+            (NearToken::from_near(1000), "1.000"),
+            (NearToken::from_millinear(1_234_567_500), "1.234.567,5"),
+            (
+                NearToken::MAX,
+                "340.282.366.920.938,463463374607431768211455",
+            ),
+        ] {
+            assert_eq!(formatter.format(tokens).to_string(), expected);
+        }
+        assert_eq!(
+            NearTokenFormatter::new()
+                .thousands_separator(Some('\u{202f}'))
+                .format(NearToken::from_near(1_000_000))
+                .to_string(),
+            "1\u{202f}000\u{202f}000 NEAR"
+        );
+
+        // the longest output: every digit of `u128::MAX` separated with a 4-byte separator
+        let digits = u128::MAX.to_string();
+        let separated = digits
+            .chars()
+            .map(String::from)
+            .collect::<Vec<_>>()
+            .join("\u{1d11e}");
+        assert_eq!(
+            NearTokenFormatter::new()
+                .unit(NearUnit::YoctoNear)
+                .thousands_separator(Some('\u{1d11e}'))
+                .grouping(1, 1)
+                .format_delta(NearTokenDelta::MIN)
+                .to_string(),
+            format!("-{} yoctoNEAR", separated)
+        );
+    }
+
+    #[test]
+    fn small_amounts() {
+        let formatter = NearTokenFormatter::new()
+            .max_fractional_digits(3)
+            .show_less_than(true)
+            .compact_zero(true)
+            .min_fractional_digits(3);
+        assert_eq!(formatter.format(NearToken::ZERO).to_string(), "0 NEAR");
+        assert_eq!(
+            formatter.format(NearToken::from_yoctonear(1)).to_string(),
+            "<0.001 NEAR"
+        );
+        assert_eq!(
+            formatter
+                .compact_zero(false)
+                .format(NearToken::ZERO)
+                .to_string(),
+            "0.000 NEAR"
+        );
+        assert_eq!(
+            formatter
+                .show_less_than(false)
+                .format(NearToken::from_yoctonear(1))
+                .to_string(),
+            "0.000 NEAR"
+        );
+        assert_eq!(
+            formatter
+                .yoctonear_threshold(Some(10))
+                .format(NearToken::from_yoctonear(10))
+                .to_string(),
+            "10 yoctoNEAR"
+        );
+    }
+
+    #[test]
+    fn presets_match_display() {
+        for tokens in [
+            NearToken::ZERO,
+            NearToken::from_yoctonear(1),
+            NearToken::from_yoctonear(1_000),
+            NearToken::from_yoctonear(1_001),
+            NearToken::from_millinear(999),
+            NearToken::from_yoctonear(999_000_000_000_000_000_000_001),
+            NearToken::from_millinear(1234),
+            NearToken::MAX,
+        ] {
+            assert_eq!(
+                NearTokenFormatter::compact().format(tokens).to_string(),
+                tokens.to_string()
+            );
+            #[cfg(feature = "alloc")]
+            assert_eq!(
+                NearTokenFormatter::exact().format(tokens).to_string(),
+                tokens.exact_amount_display()
+            );
+        }
+    }
+
+    #[test]
+    fn exact_fast_path() {
+        for tokens in [
+            NearToken::ZERO,
+            NearToken::from_yoctonear(1),
+            NearToken::from_yoctonear(1_000),
+            NearToken::from_yoctonear(1_001),
+            NearToken::from_near(1),
+            NearToken::from_near(1_000_000),
+            NearToken::from_yoctonear(1_000_000_000_001_000_000_000_000),
+            NearToken::from_yoctonear(1_234_567_890_123_456_789_000_000),
+            NearToken::from_yoctonear(999_999_999_999_999_999_999_999),
+            NearToken::MAX,
+        ] {
+            let mut general = String::new();
+            NearTokenFormatter::exact()
+                .write_amount(&mut general, tokens)
+                .unwrap();
+            let mut fast = String::new();
+            super::write_exact(&mut fast, tokens).unwrap();
+            assert_eq!(fast, general);
+            assert_eq!(tokens.exact().to_string(), general);
+        }
+    }
+
+    #[test]
+    fn delta() {
+        let formatter = NearTokenFormatter::new();
+        assert_eq!(
+            formatter
+                .format_delta(NearTokenDelta::from_yoctonear(-5))
+                .to_string(),
+            "-0.000000000000000000000005 NEAR"
+        );
+        assert_eq!(
+            formatter
+                .format_delta(NearTokenDelta::positive(NearToken::from_near(5)))
+                .to_string(),
+            "+5 NEAR"
+        );
+        assert_eq!(
+            formatter.format_delta(NearTokenDelta::ZERO).to_string(),
+            "0 NEAR"
+        );
+    }
+
+    #[test]
+    fn padding() {
+        let formatted = NearTokenFormatter::new().format(NearToken::from_near(5));
+        assert_eq!(format!("{:>8}|", formatted), "  5 NEAR|");
+        assert_eq!(format!("{:-^10}|", formatted), "--5 NEAR--|");
+        assert_eq!(format!("{:+}", formatted), "+5 NEAR");
+    }
+}
@@ -14,24 +14,71 @@
 //!
 //! # Crate features
 //!
+//! * **std** (enabled by default) -
+//!   Implements `std::error::Error` for the error types. Implies **alloc**.
+//!   Without it the crate is `#![no_std]` and only depends on `core`.
+//!
+//! * **alloc** (enabled by default through **std**) -
+//!   Enables the APIs that return `String` or `Vec`, such as `NearToken::exact_amount_display`
+//!   and `NearToken::split_proportionally`, and the `FromStr` implementations, whose `NearTokenError`
+//!   carries the offending input. Without it amounts are parsed with `NearToken::parse_const`,
+//!   which returns the allocation-free `ParseError`.
+//!
 //! * **borsh** (optional) -
-//!   When enabled allows `NearToken` to serialized and deserialized by `borsh`.
+//!   When enabled allows `NearToken` and `NearTokenDelta` to serialized and deserialized by `borsh`.
 //!
 //! * **serde** (optional) -
-//!   When enabled allows `NearToken` to serialized and deserialized by `serde`.
+//!   When enabled allows `NearToken` and `NearTokenDelta` to serialized and deserialized by `serde`,
+//!   and provides alternative representations in the [`serde`](crate::serde) module.
 //!
 //! * **schemars** (optional) -
-//!   Implements `schemars::JsonSchema` for `NearToken`.
+//!   Implements `schemars::JsonSchema` for `NearToken`. Implies **std**.
 //!
 //! * **interactive-clap** (optional) -
-//!   Implements `interactive_clap::ToCli` for `NearToken`.
+//!   Implements `interactive_clap::ToCli` for `NearToken`. Implies **std**.
+// Unit tests always link std for the test harness, the library itself is `no_std` without the `std` feature.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod delta;
+
 mod error;
 
+mod format;
+
+mod locale;
+
+mod parse;
+
+mod ratio;
+
+mod rounding;
+
+#[cfg(feature = "alloc")]
+mod split;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
 mod utils;
 
 mod trait_impls;
 
+mod unit;
+
+pub use self::delta::NearTokenDelta;
+#[cfg(feature = "alloc")]
 pub use self::error::NearTokenError;
+pub use self::error::{ParseDiagnostic, ParseError, ParseErrorKind, SplitError};
+pub use self::format::{FormattedNearToken, NearTokenFormatter};
+pub use self::locale::{DecimalSeparator, Locale, UnitPlacement};
+pub use self::parse::{GroupSeparator, ParseOptions};
+pub use self::ratio::Ratio;
+pub use self::rounding::Rounding;
+pub use self::unit::NearUnit;
+#[cfg(feature = "alloc")]
 pub use self::utils::DecimalNumberParsingError;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
@@ -45,9 +92,9 @@ pub struct NearToken {
     inner: u128,
 }
 
-const ONE_NEAR: u128 = 10_u128.pow(24);
-const ONE_MILLINEAR: u128 = 10_u128.pow(21);
-const ONE_MICRONEAR: u128 = 10_u128.pow(18);
+const ONE_NEAR: u128 = NearUnit::Near.multiplier();
+const ONE_MILLINEAR: u128 = NearUnit::MilliNear.multiplier();
+const ONE_MICRONEAR: u128 = NearUnit::MicroNear.multiplier();
 
 impl NearToken {
     /// Maximum value for NearToken (u128::MAX)
@@ -101,6 +148,19 @@ impl NearToken {
         }
     }
 
+    /// `from_unit` is a function that takes value by a number of the given units and converts it to an equivalent to the yocto-near.
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearUnit};
+    /// assert_eq!(NearToken::from_unit(2, NearUnit::KiloNear), NearToken::from_near(2_000));
+    /// assert_eq!(NearToken::from_unit(5, NearUnit::NanoNear), NearToken::from_yoctonear(5 * 10u128.pow(15)));
+    /// ```
+    pub const fn from_unit(inner: u128, unit: NearUnit) -> Self {
+        Self {
+            inner: inner * unit.multiplier(),
+        }
+    }
+
     /// `as_near` is a function that converts number of yocto-near to an equivalent to the near.
     /// # Examples
     /// ```
@@ -131,6 +191,17 @@ impl NearToken {
         self.inner / ONE_MILLINEAR
     }
 
+    /// `as_unit` is a function that converts number of yocto-near to an equivalent to the given units, rounding down.
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearUnit};
+    /// assert_eq!(NearToken::from_near(2_500).as_unit(NearUnit::KiloNear), 2);
+    /// assert_eq!(NearToken::from_millinear(1).as_unit(NearUnit::NanoNear), 1_000_000);
+    /// ```
+    pub const fn as_unit(&self, unit: NearUnit) -> u128 {
+        self.inner / unit.multiplier()
+    }
+
     /// `as_yoctonear` is a function that shows a number of yocto-near.
     /// # Examples
     /// ```
@@ -269,6 +340,253 @@ impl NearToken {
         NearToken::from_yoctonear(self.as_yoctonear().saturating_div(rhs))
     }
 
+    /// Checked integer remainder. Computes self % rhs, returning None if rhs == 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(10).checked_rem(3), Some(NearToken::from_yoctonear(1)));
+    /// assert_eq!(NearToken::from_yoctonear(10).checked_rem(0), None);
+    /// ```
+    pub const fn checked_rem(self, rhs: u128) -> Option<Self> {
+        if let Some(near) = self.as_yoctonear().checked_rem(rhs) {
+            Some(Self::from_yoctonear(near))
+        } else {
+            None
+        }
+    }
+
+    /// Checked multiplication followed by division. Computes self * num / den with the given rounding,
+    /// returning None if den == 0 or the result does not fit into `NearToken`.
+    ///
+    /// The intermediate product is computed with 256-bit precision, so it never overflows on its own.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, Rounding};
+    /// let tokens = NearToken::MAX;
+    /// assert_eq!(tokens.checked_mul_div(3, 3, Rounding::Down), Some(NearToken::MAX));
+    /// assert_eq!(NearToken::from_yoctonear(10).checked_mul_div(2, 3, Rounding::Down), Some(NearToken::from_yoctonear(6)));
+    /// assert_eq!(NearToken::from_yoctonear(10).checked_mul_div(2, 3, Rounding::Up), Some(NearToken::from_yoctonear(7)));
+    /// assert_eq!(tokens.checked_mul_div(4, 3, Rounding::Down), None);
+    /// assert_eq!(tokens.checked_mul_div(1, 0, Rounding::Down), None);
+    /// ```
+    pub const fn checked_mul_div(self, num: u128, den: u128, rounding: Rounding) -> Option<Self> {
+        let (quotient, remainder) = match utils::mul_div_rem(self.as_yoctonear(), num, den) {
+            Some(result) => result,
+            None => return None,
+        };
+        if let Some(near) = rounding.round_quotient(quotient, remainder, den) {
+            Some(Self::from_yoctonear(near))
+        } else {
+            None
+        }
+    }
+
+    /// Wrapping integer addition. Computes self + rhs, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(5).wrapping_add(NearToken::from_yoctonear(5)), NearToken::from_yoctonear(10));
+    /// assert_eq!(NearToken::from_yoctonear(u128::MAX).wrapping_add(NearToken::from_yoctonear(2)), NearToken::from_yoctonear(1));
+    /// ```
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        NearToken::from_yoctonear(self.as_yoctonear().wrapping_add(rhs.as_yoctonear()))
+    }
+
+    /// Wrapping integer subtraction. Computes self - rhs, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(5).wrapping_sub(NearToken::from_yoctonear(2)), NearToken::from_yoctonear(3));
+    /// assert_eq!(NearToken::from_yoctonear(1).wrapping_sub(NearToken::from_yoctonear(2)), NearToken::from_yoctonear(u128::MAX));
+    /// ```
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        NearToken::from_yoctonear(self.as_yoctonear().wrapping_sub(rhs.as_yoctonear()))
+    }
+
+    /// Wrapping integer multiplication. Computes self * rhs, wrapping around at the boundary of the type.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(2).wrapping_mul(5), NearToken::from_yoctonear(10));
+    /// assert_eq!(NearToken::from_yoctonear(u128::MAX).wrapping_mul(2), NearToken::from_yoctonear(u128::MAX - 1));
+    /// ```
+    pub const fn wrapping_mul(self, rhs: u128) -> Self {
+        NearToken::from_yoctonear(self.as_yoctonear().wrapping_mul(rhs))
+    }
+
+    /// Wrapping integer division. Computes self / rhs. Unsigned division never wraps,
+    /// so this is the same as the `/` operator and exists for parity with the primitive integers.
+    ///
+    /// # Panics
+    /// Panics if rhs == 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(10).wrapping_div(3), NearToken::from_yoctonear(3));
+    /// ```
+    pub const fn wrapping_div(self, rhs: u128) -> Self {
+        NearToken::from_yoctonear(self.as_yoctonear().wrapping_div(rhs))
+    }
+
+    /// Wrapping integer remainder. Computes self % rhs. Unsigned remainder never wraps,
+    /// so this is the same as the `%` operator and exists for parity with the primitive integers.
+    ///
+    /// # Panics
+    /// Panics if rhs == 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(10).wrapping_rem(3), NearToken::from_yoctonear(1));
+    /// ```
+    pub const fn wrapping_rem(self, rhs: u128) -> Self {
+        NearToken::from_yoctonear(self.as_yoctonear().wrapping_rem(rhs))
+    }
+
+    /// Overflowing integer addition. Computes self + rhs, returning a tuple of the wrapped result
+    /// along with a boolean indicating whether an arithmetic overflow would occur.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(5).overflowing_add(NearToken::from_yoctonear(5)), (NearToken::from_yoctonear(10), false));
+    /// assert_eq!(NearToken::from_yoctonear(u128::MAX).overflowing_add(NearToken::from_yoctonear(2)), (NearToken::from_yoctonear(1), true));
+    /// ```
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (near, overflowed) = self.as_yoctonear().overflowing_add(rhs.as_yoctonear());
+        (NearToken::from_yoctonear(near), overflowed)
+    }
+
+    /// Overflowing integer subtraction. Computes self - rhs, returning a tuple of the wrapped result
+    /// along with a boolean indicating whether an arithmetic overflow would occur.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(5).overflowing_sub(NearToken::from_yoctonear(2)), (NearToken::from_yoctonear(3), false));
+    /// assert_eq!(NearToken::from_yoctonear(1).overflowing_sub(NearToken::from_yoctonear(2)), (NearToken::from_yoctonear(u128::MAX), true));
+    /// ```
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (near, overflowed) = self.as_yoctonear().overflowing_sub(rhs.as_yoctonear());
+        (NearToken::from_yoctonear(near), overflowed)
+    }
+
+    /// Overflowing integer multiplication. Computes self * rhs, returning a tuple of the wrapped result
+    /// along with a boolean indicating whether an arithmetic overflow would occur.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(2).overflowing_mul(5), (NearToken::from_yoctonear(10), false));
+    /// assert_eq!(NearToken::from_yoctonear(u128::MAX).overflowing_mul(2), (NearToken::from_yoctonear(u128::MAX - 1), true));
+    /// ```
+    pub const fn overflowing_mul(self, rhs: u128) -> (Self, bool) {
+        let (near, overflowed) = self.as_yoctonear().overflowing_mul(rhs);
+        (NearToken::from_yoctonear(near), overflowed)
+    }
+
+    /// Overflowing integer division. Computes self / rhs, returning a tuple of the result
+    /// along with a boolean indicating whether an arithmetic overflow would occur, which is always false
+    /// for unsigned division.
+    ///
+    /// # Panics
+    /// Panics if rhs == 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(10).overflowing_div(3), (NearToken::from_yoctonear(3), false));
+    /// ```
+    pub const fn overflowing_div(self, rhs: u128) -> (Self, bool) {
+        let (near, overflowed) = self.as_yoctonear().overflowing_div(rhs);
+        (NearToken::from_yoctonear(near), overflowed)
+    }
+
+    /// Overflowing integer remainder. Computes self % rhs, returning a tuple of the result
+    /// along with a boolean indicating whether an arithmetic overflow would occur, which is always false
+    /// for unsigned remainder.
+    ///
+    /// # Panics
+    /// Panics if rhs == 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_yoctonear(10).overflowing_rem(3), (NearToken::from_yoctonear(1), false));
+    /// ```
+    pub const fn overflowing_rem(self, rhs: u128) -> (Self, bool) {
+        let (near, overflowed) = self.as_yoctonear().overflowing_rem(rhs);
+        (NearToken::from_yoctonear(near), overflowed)
+    }
+
+    /// Checked sum of all the tokens in the iterator, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// let balances = [NearToken::from_near(1), NearToken::from_millinear(500)];
+    /// assert_eq!(NearToken::checked_sum(balances), Some(NearToken::from_millinear(1500)));
+    /// assert_eq!(NearToken::checked_sum([NearToken::MAX, NearToken::from_yoctonear(1)]), None);
+    /// assert_eq!(NearToken::checked_sum([]), Some(NearToken::ZERO));
+    /// ```
+    pub fn checked_sum<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = NearToken>,
+    {
+        iter.into_iter()
+            .try_fold(NearToken::ZERO, NearToken::checked_add)
+    }
+
+    /// Saturating sum of all the tokens in the iterator, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// let balances = [NearToken::from_near(1), NearToken::from_millinear(500)];
+    /// assert_eq!(NearToken::saturating_sum(balances), NearToken::from_millinear(1500));
+    /// assert_eq!(NearToken::saturating_sum([NearToken::MAX, NearToken::from_yoctonear(1)]), NearToken::MAX);
+    /// ```
+    pub fn saturating_sum<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = NearToken>,
+    {
+        let mut sum = NearToken::ZERO;
+        for tokens in iter {
+            sum = sum.saturating_add(tokens);
+            if sum == NearToken::MAX {
+                break;
+            }
+        }
+        sum
+    }
+
+    /// Returns an adapter that displays the exact amount in NEAR or yoctoNEAR depending on the value,
+    /// writing it directly into the formatter without heap allocations.
+    ///
+    /// Prefer it over [`NearToken::exact_amount_display`] when the result is written into an existing buffer,
+    /// e.g. when dumping a large number of balances.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::fmt::Write;
+    /// use near_token::NearToken;
+    ///
+    /// let mut out = String::new();
+    /// for tokens in [NearToken::from_near(1), NearToken::from_yoctonear(500)] {
+    ///     writeln!(out, "{}", tokens.exact()).unwrap();
+    /// }
+    /// assert_eq!(out, "1 NEAR\n500 yoctoNEAR\n");
+    /// ```
+    pub const fn exact(&self) -> FormattedNearToken {
+        NearTokenFormatter::exact().format(*self)
+    }
+
     /// Formats the `NearToken` and displays the amount in NEAR or yoctoNEAR depending on the value.
     ///
     /// # Examples
@@ -279,22 +597,30 @@ impl NearToken {
     /// assert_eq!(NearToken::from_yoctonear(500).exact_amount_display(), "500 yoctoNEAR");
     /// assert_eq!(NearToken::from_yoctonear(0).exact_amount_display(), "0 NEAR");
     /// ```
-    pub fn exact_amount_display(&self) -> String {
-        let yoctonear = self.as_yoctonear();
-
-        if yoctonear == 0 {
-            "0 NEAR".to_string()
-        } else if yoctonear <= 1_000 {
-            format!("{} yoctoNEAR", yoctonear)
-        } else if yoctonear % ONE_NEAR == 0 {
-            format!("{} NEAR", yoctonear / ONE_NEAR)
-        } else {
-            format!(
-                "{}.{} NEAR",
-                yoctonear / ONE_NEAR,
-                format!("{:0>24}", yoctonear % ONE_NEAR).trim_end_matches('0')
-            )
+    #[cfg(feature = "alloc")]
+    pub fn exact_amount_display(&self) -> alloc::string::String {
+        // the most common amount needs no formatting at all
+        if self.is_zero() {
+            return "0 NEAR".into();
         }
+        // Enough for any exact amount, e.g. `340282366920938.463463374607431768211455 NEAR`, to avoid reallocations.
+        let mut out = alloc::string::String::with_capacity(48);
+        crate::format::write_exact(&mut out, *self).expect("writing into a String never fails");
+        out
+    }
+
+    /// Formats the exact amount in the given unit with trailing zeros trimmed.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearUnit};
+    /// assert_eq!(NearToken::from_near(1_500).to_string_in(NearUnit::KiloNear), "1.5 kiloNEAR");
+    /// assert_eq!(NearToken::from_yoctonear(1).to_string_in(NearUnit::NanoNear), "0.000000000000001 nanoNEAR");
+    /// assert_eq!(NearToken::ZERO.to_string_in(NearUnit::MegaNear), "0 megaNEAR");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_string_in(&self, unit: NearUnit) -> alloc::string::String {
+        alloc::string::ToString::to_string(&NearTokenFormatter::new().unit(unit).format(*self))
     }
 }
 
@@ -399,6 +725,143 @@ mod test {
     }
 
     #[test]
+    fn checked_rem_tokens() {
+        let tokens = NearToken::from_yoctonear(10);
+        assert_eq!(tokens.checked_rem(3), Some(NearToken::from_yoctonear(1)));
+        assert_eq!(tokens.checked_rem(11), Some(NearToken::from_yoctonear(10)));
+        assert_eq!(tokens.checked_rem(0), None);
+    }
+
+    #[test]
+    fn checked_mul_div_tokens() {
+        use crate::Rounding;
+
+        let tokens = NearToken::from_yoctonear(u128::MAX / 3);
+        assert_eq!(tokens.checked_mul_div(3, 3, Rounding::Down), Some(tokens));
+        assert_eq!(
+            NearToken::MAX.checked_mul_div(u128::MAX, u128::MAX, Rounding::Up),
+            Some(NearToken::MAX)
+        );
+        assert_eq!(NearToken::MAX.checked_mul_div(2, 1, Rounding::Down), None);
+        assert_eq!(tokens.checked_mul_div(1, 0, Rounding::Down), None);
+        // 1 NEAR * 1 / 6 = 166666666666666666666666.(6) yoctoNEAR
+        for (rounding, expected) in [
+            (Rounding::Down, 166_666_666_666_666_666_666_666),
+            (Rounding::TowardZero, 166_666_666_666_666_666_666_666),
+            (Rounding::Up, 166_666_666_666_666_666_666_667),
+            (Rounding::HalfUp, 166_666_666_666_666_666_666_667),
+            (Rounding::HalfEven, 166_666_666_666_666_666_666_667),
+        ] {
+            assert_eq!(
+                NearToken::from_near(1).checked_mul_div(1, 6, rounding),
+                Some(NearToken::from_yoctonear(expected))
+            );
+        }
+        // A tie: 5 / 2 = 2.5 yoctoNEAR
+        for (rounding, expected) in [
+            (Rounding::Down, 2),
+            (Rounding::Up, 3),
+            (Rounding::HalfUp, 3),
+            (Rounding::HalfEven, 2),
+        ] {
+            assert_eq!(
+                NearToken::from_yoctonear(5).checked_mul_div(1, 2, rounding),
+                Some(NearToken::from_yoctonear(expected))
+            );
+        }
+        assert_eq!(
+            NearToken::MAX.checked_mul_div(1, 2, Rounding::Up),
+            Some(NearToken::from_yoctonear(u128::MAX / 2 + 1))
+        );
+    }
+
+    #[test]
+    fn wrapping_tokens() {
+        let tokens = NearToken::from_yoctonear(u128::MAX);
+        let rhs_tokens = NearToken::from_yoctonear(3);
+        assert_eq!(
+            tokens.wrapping_add(rhs_tokens),
+            NearToken::from_yoctonear(2)
+        );
+        assert_eq!(
+            NearToken::ZERO.wrapping_sub(rhs_tokens),
+            NearToken::from_yoctonear(u128::MAX - 2)
+        );
+        assert_eq!(
+            tokens.wrapping_mul(3),
+            NearToken::from_yoctonear(u128::MAX - 2)
+        );
+        assert_eq!(rhs_tokens.wrapping_mul(3), NearToken::from_yoctonear(9));
+        assert_eq!(tokens.wrapping_div(1), tokens);
+        assert_eq!(tokens.wrapping_div(u128::MAX), NearToken::from_yoctonear(1));
+        assert_eq!(tokens.wrapping_rem(2), NearToken::from_yoctonear(1));
+        assert_eq!(rhs_tokens.wrapping_rem(5), rhs_tokens);
+    }
+
+    #[test]
+    fn overflowing_tokens() {
+        let tokens = NearToken::from_yoctonear(u128::MAX);
+        let rhs_tokens = NearToken::from_yoctonear(3);
+        assert_eq!(
+            tokens.overflowing_add(rhs_tokens),
+            (NearToken::from_yoctonear(2), true)
+        );
+        assert_eq!(
+            rhs_tokens.overflowing_add(rhs_tokens),
+            (NearToken::from_yoctonear(6), false)
+        );
+        assert_eq!(
+            NearToken::ZERO.overflowing_sub(rhs_tokens),
+            (NearToken::from_yoctonear(u128::MAX - 2), true)
+        );
+        assert_eq!(
+            tokens.overflowing_mul(3),
+            (NearToken::from_yoctonear(u128::MAX - 2), true)
+        );
+        assert_eq!(
+            rhs_tokens.overflowing_mul(3),
+            (NearToken::from_yoctonear(9), false)
+        );
+        assert_eq!(tokens.overflowing_div(1), (tokens, false));
+        assert_eq!(
+            tokens.overflowing_rem(2),
+            (NearToken::from_yoctonear(1), false)
+        );
+    }
+
+    #[test]
+    fn checked_sum_tokens() {
+        let tokens = [
+            NearToken::from_yoctonear(u128::MAX - 3),
+            NearToken::from_yoctonear(3),
+        ];
+        assert_eq!(
+            NearToken::checked_sum(tokens),
+            Some(NearToken::from_yoctonear(u128::MAX))
+        );
+        assert_eq!(
+            NearToken::checked_sum(tokens.into_iter().chain([NearToken::from_yoctonear(1)])),
+            None
+        );
+        assert_eq!(NearToken::checked_sum([]), Some(NearToken::ZERO));
+    }
+
+    #[test]
+    fn saturating_sum_tokens() {
+        let tokens = [NearToken::from_yoctonear(100), NearToken::from_yoctonear(1)];
+        assert_eq!(
+            NearToken::saturating_sum(tokens),
+            NearToken::from_yoctonear(101)
+        );
+        assert_eq!(
+            NearToken::saturating_sum(tokens.into_iter().chain([NearToken::MAX])),
+            NearToken::MAX
+        );
+        assert_eq!(NearToken::saturating_sum([]), NearToken::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
     fn exact_amount_display_tokens() {
         let token = NearToken::from_yoctonear(0);
         assert_eq!(token.exact_amount_display(), "0 NEAR");
@@ -415,4 +878,42 @@ mod test {
         let token = NearToken::from_yoctonear(1_234_567_890_123_456_789_000_000);
         assert_eq!(token.exact_amount_display(), "1.234567890123456789 NEAR");
     }
+
+    #[test]
+    fn exact_tokens() {
+        #[cfg(feature = "alloc")]
+        for token in [
+            NearToken::ZERO,
+            NearToken::from_yoctonear(1),
+            NearToken::from_yoctonear(1_000),
+            NearToken::from_yoctonear(1_001),
+            NearToken::from_millinear(1_500),
+            NearToken::MAX,
+        ] {
+            assert_eq!(token.exact().to_string(), token.exact_amount_display());
+        }
+        assert_eq!(
+            format!("{:>12}", NearToken::from_millinear(1_500).exact()),
+            "    1.5 NEAR"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn units_round_trip() {
+        let tokens = NearToken::from_yoctonear(1_234_567_890_123_456_789_012_345_678_901);
+        for unit in crate::NearUnit::ALL {
+            assert_eq!(
+                NearToken::from_unit(tokens.as_unit(unit), unit),
+                NearToken::from_yoctonear(
+                    tokens.as_yoctonear() / unit.multiplier() * unit.multiplier()
+                )
+            );
+            assert_eq!(tokens.to_string_in(unit).parse(), Ok(tokens));
+            assert_eq!(
+                NearToken::MAX.to_string_in(unit).parse(),
+                Ok(NearToken::MAX)
+            );
+        }
+    }
 }
@@ -0,0 +1,371 @@
+use crate::{FormattedNearToken, NearToken, NearTokenFormatter, ParseError, ParseOptions};
+
+/// Separator between the integer and the fractional parts of an amount.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalSeparator {
+    /// `.`, e.g. `1.5 NEAR`
+    #[default]
+    Point,
+    /// `,`, e.g. `1,5 NEAR`
+    Comma,
+}
+
+impl DecimalSeparator {
+    /// Returns the separator character.
+    pub const fn as_char(self) -> char {
+        self.as_byte() as char
+    }
+
+    pub(crate) const fn as_byte(self) -> u8 {
+        match self {
+            DecimalSeparator::Point => b'.',
+            DecimalSeparator::Comma => b',',
+        }
+    }
+}
+
+/// Position of the unit relative to the number.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitPlacement {
+    /// The unit follows the number, e.g. `1.5 NEAR`
+    #[default]
+    Suffix,
+    /// The unit precedes the number, e.g. `NEAR 1.5`
+    Prefix,
+}
+
+/// Conventions of writing amounts in a locale: decimal separator, digit grouping and unit placement.
+///
+/// A locale drives both [`NearTokenFormatter::locale`] and [`ParseOptions::locale`], so amounts displayed
+/// for a locale can be parsed back with it. Common locales are bundled, see [`Locale::bundled`],
+/// and custom ones can be built starting from [`Locale::new`].
+///
+/// # Examples
+/// ```
+/// use near_token::{Locale, NearToken};
+///
+/// let tokens = NearToken::from_millinear(1_234_560);
+/// assert_eq!(Locale::DE_DE.format(tokens).to_string(), "1.234,56 NEAR");
+/// assert_eq!(Locale::EN_IN.format(NearToken::from_near(123_456)).to_string(), "1,23,456.00 NEAR");
+/// assert_eq!(Locale::DE_DE.parse("1.234,56 NEAR"), Ok(tokens));
+///
+/// let locale = Locale::from_tag("fr").unwrap();
+/// assert_eq!(locale, Locale::FR_FR);
+/// assert_eq!(locale.parse("1\u{202f}234,56 NEAR"), Ok(tokens));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale {
+    pub(crate) decimal_separator: DecimalSeparator,
+    pub(crate) group_separator: Option<char>,
+    pub(crate) primary_group: u8,
+    pub(crate) secondary_group: u8,
+    pub(crate) unit_placement: UnitPlacement,
+}
+
+impl Locale {
+    /// English (United States): `1,234,567.89 NEAR`
+    pub const EN_US: Locale = Locale::new();
+    /// English (United Kingdom): `1,234,567.89 NEAR`
+    pub const EN_GB: Locale = Locale::new();
+    /// English (India): `12,34,567.89 NEAR`
+    pub const EN_IN: Locale = Locale::new().grouping(3, 2);
+    /// Hindi (India): `12,34,567.89 NEAR`
+    pub const HI_IN: Locale = Locale::EN_IN;
+    /// German (Germany): `1.234.567,89 NEAR`
+    pub const DE_DE: Locale = Locale::new()
+        .decimal_separator(DecimalSeparator::Comma)
+        .group_separator(Some('.'));
+    /// German (Austria): `1 234 567,89 NEAR` with no-break spaces
+    pub const DE_AT: Locale = Locale::new()
+        .decimal_separator(DecimalSeparator::Comma)
+        .group_separator(Some('\u{a0}'));
+    /// German (Switzerland): `1’234’567.89 NEAR`
+    pub const DE_CH: Locale = Locale::new().group_separator(Some('\u{2019}'));
+    /// French (France): `1 234 567,89 NEAR` with narrow no-break spaces
+    pub const FR_FR: Locale = Locale::new()
+        .decimal_separator(DecimalSeparator::Comma)
+        .group_separator(Some('\u{202f}'));
+    /// Spanish (Spain): `1.234.567,89 NEAR`
+    pub const ES_ES: Locale = Locale::DE_DE;
+    /// Italian (Italy): `1.234.567,89 NEAR`
+    pub const IT_IT: Locale = Locale::DE_DE;
+    /// Dutch (Netherlands): `1.234.567,89 NEAR`
+    pub const NL_NL: Locale = Locale::DE_DE;
+    /// Portuguese (Brazil): `1.234.567,89 NEAR`
+    pub const PT_BR: Locale = Locale::DE_DE;
+    /// Turkish (Turkey): `1.234.567,89 NEAR`
+    pub const TR_TR: Locale = Locale::DE_DE;
+    /// Polish (Poland): `1 234 567,89 NEAR` with no-break spaces
+    pub const PL_PL: Locale = Locale::DE_AT;
+    /// Russian (Russia): `1 234 567,89 NEAR` with no-break spaces
+    pub const RU_RU: Locale = Locale::DE_AT;
+    /// Ukrainian (Ukraine): `1 234 567,89 NEAR` with no-break spaces
+    pub const UK_UA: Locale = Locale::DE_AT;
+    /// Japanese (Japan): `1,234,567.89 NEAR`
+    pub const JA_JP: Locale = Locale::new();
+    /// Korean (South Korea): `1,234,567.89 NEAR`
+    pub const KO_KR: Locale = Locale::new();
+    /// Chinese (China): `1,234,567.89 NEAR`
+    pub const ZH_CN: Locale = Locale::new();
+
+    /// Creates a locale with the English conventions: `.` as the decimal separator,
+    /// `,` between groups of three digits and the unit after the number.
+    pub const fn new() -> Self {
+        Self {
+            decimal_separator: DecimalSeparator::Point,
+            group_separator: Some(','),
+            primary_group: 3,
+            secondary_group: 3,
+            unit_placement: UnitPlacement::Suffix,
+        }
+    }
+
+    /// Sets the separator between the integer and the fractional parts.
+    pub const fn decimal_separator(mut self, separator: DecimalSeparator) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the separator between groups of digits in the integer part, or disables grouping.
+    ///
+    /// When parsing, a separator that is one of the [`GroupSeparator`](crate::GroupSeparator)s also accepts
+    /// its alternatives, e.g. `'\u{a0}'` accepts a regular space, while any other one is accepted only as it is.
+    pub const fn group_separator(mut self, separator: Option<char>) -> Self {
+        self.group_separator = separator;
+        self
+    }
+
+    /// Sets the sizes of digit groups: the group nearest to the decimal separator has `primary` digits,
+    /// and the other ones have `secondary` digits, e.g. `(3, 2)` for `12,34,567`.
+    ///
+    /// # Panics
+    /// Panics if any of the sizes is zero.
+    pub const fn grouping(mut self, primary: u8, secondary: u8) -> Self {
+        assert!(
+            primary != 0 && secondary != 0,
+            "digit group sizes must not be zero"
+        );
+        self.primary_group = primary;
+        self.secondary_group = secondary;
+        self
+    }
+
+    /// Sets the position of the unit relative to the number.
+    pub const fn unit_placement(mut self, placement: UnitPlacement) -> Self {
+        self.unit_placement = placement;
+        self
+    }
+
+    /// Returns the bundled locales along with their BCP 47 language tags.
+    pub const fn bundled() -> &'static [(&'static str, Locale)] {
+        &BUNDLED_LOCALES
+    }
+
+    /// Finds a bundled locale by its language tag, e.g. `de-DE`, `de_de` or just `de`.
+    ///
+    /// A tag with a language only selects the first bundled locale of that language.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::Locale;
+    /// assert_eq!(Locale::from_tag("en-IN"), Some(Locale::EN_IN));
+    /// assert_eq!(Locale::from_tag("DE_ch"), Some(Locale::DE_CH));
+    /// assert_eq!(Locale::from_tag("it"), Some(Locale::IT_IT));
+    /// assert_eq!(Locale::from_tag("xx-XX"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let tag = tag.trim();
+        let same_tag = |bundled: &str| {
+            bundled.len() == tag.len()
+                && bundled.bytes().zip(tag.bytes()).all(|(bundled, byte)| {
+                    bundled.eq_ignore_ascii_case(&byte) || (bundled == b'-' && byte == b'_')
+                })
+        };
+        let same_language = |bundled: &str| {
+            bundled
+                .split('-')
+                .next()
+                .map_or(false, |language| language.eq_ignore_ascii_case(tag))
+        };
+        BUNDLED_LOCALES
+            .iter()
+            .find(|(bundled, _)| same_tag(bundled))
+            .or_else(|| {
+                BUNDLED_LOCALES
+                    .iter()
+                    .find(|(bundled, _)| same_language(bundled))
+            })
+            .map(|&(_, locale)| locale)
+    }
+
+    /// Returns a wrapper that displays `tokens` like `NearToken` Display implementation does,
+    /// but with the conventions of this locale.
+    pub const fn format(&self, tokens: NearToken) -> FormattedNearToken {
+        NearTokenFormatter::compact().locale(self).format(tokens)
+    }
+
+    /// Parses an amount written with the conventions of this locale, see [`ParseOptions::locale`].
+    pub const fn parse(&self, s: &str) -> Result<NearToken, ParseError> {
+        NearToken::parse_with(s, &ParseOptions::new().locale(self))
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const BUNDLED_LOCALES: [(&str, Locale); 20] = [
+    ("en-US", Locale::EN_US),
+    ("en-GB", Locale::EN_GB),
+    ("en-IN", Locale::EN_IN),
+    ("hi-IN", Locale::HI_IN),
+    ("de-DE", Locale::DE_DE),
+    ("de-AT", Locale::DE_AT),
+    ("de-CH", Locale::DE_CH),
+    ("fr-FR", Locale::FR_FR),
+    ("es-ES", Locale::ES_ES),
+    ("it-IT", Locale::IT_IT),
+    ("nl-NL", Locale::NL_NL),
+    ("pt-BR", Locale::PT_BR),
+    ("tr-TR", Locale::TR_TR),
+    ("pl-PL", Locale::PL_PL),
+    ("ru-RU", Locale::RU_RU),
+    ("uk-UA", Locale::UK_UA),
+    ("ja-JP", Locale::JA_JP),
+    ("ko-KR", Locale::KO_KR),
+    ("zh-CN", Locale::ZH_CN),
+    ("zh-TW", Locale::ZH_CN),
+];
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        DecimalSeparator, Locale, NearToken, NearTokenFormatter, ParseError, UnitPlacement,
+    };
+
+    #[test]
+    fn format_and_parse_bundled() {
+        let tokens = NearToken::from_millinear(1_234_567_890);
+        for (tag, locale, expected) in [
+            ("en-US", Locale::EN_US, "1,234,567.89 NEAR"),
+            ("en-IN", Locale::EN_IN, "12,34,567.89 NEAR"),
+            ("de-DE", Locale::DE_DE, "1.234.567,89 NEAR"),
+            ("de-AT", Locale::DE_AT, "1\u{a0}234\u{a0}567,89 NEAR"),
+            ("de-CH", Locale::DE_CH, "1\u{2019}234\u{2019}567.89 NEAR"),
+            ("fr-FR", Locale::FR_FR, "1\u{202f}234\u{202f}567,89 NEAR"),
+        ] {
+            assert_eq!(Locale::from_tag(tag), Some(locale));
+            assert_eq!(locale.format(tokens).to_string(), expected);
+            assert_eq!(
+                locale.parse(expected),
+                Ok(NearToken::from_millinear(1_234_567_890))
+            );
+        }
+    }
+
+    #[test]
+    fn bundled_tags_round_trip() {
+        let tokens = NearToken::from_yoctonear(12_345_678_900_000_000_000_000_000_000_000);
+        for &(tag, locale) in Locale::bundled() {
+            assert_eq!(
+                Locale::from_tag(tag).map(|l| l.format(tokens).to_string()),
+                Some(locale.format(tokens).to_string())
+            );
+            let exact = NearTokenFormatter::new().locale(&locale).format(tokens);
+            assert_eq!(locale.parse(&exact.to_string()), Ok(tokens), "{}", tag);
+        }
+    }
+
+    #[test]
+    fn parse_locale_errors() {
+        assert_eq!(
+            Locale::DE_DE.parse("1,234,56 NEAR"),
+            Err(ParseError::InvalidNumber)
+        );
+        assert_eq!(
+            Locale::DE_DE.parse("1.234.56 NEAR"),
+            Err(ParseError::InvalidDigitGrouping)
+        );
+        assert_eq!(
+            Locale::EN_IN.parse("123,456 NEAR"),
+            Err(ParseError::InvalidDigitGrouping)
+        );
+        assert_eq!(
+            Locale::EN_IN.parse("1,23,456 NEAR"),
+            Ok(NearToken::from_near(123_456))
+        );
+        assert_eq!(
+            Locale::EN_US.parse("1,23,456 NEAR"),
+            Err(ParseError::InvalidDigitGrouping)
+        );
+        assert_eq!(Locale::from_tag(""), None);
+        assert_eq!(Locale::from_tag("en-"), None);
+    }
+
+    #[test]
+    fn unit_prefix() {
+        let locale = Locale::new()
+            .decimal_separator(DecimalSeparator::Comma)
+            .group_separator(None)
+            .unit_placement(UnitPlacement::Prefix);
+        assert_eq!(
+            locale.format(NearToken::from_millinear(1500)).to_string(),
+            "NEAR 1,50"
+        );
+        assert_eq!(
+            locale.format(NearToken::from_yoctonear(1)).to_string(),
+            "NEAR <0,001"
+        );
+        assert_eq!(
+            locale.parse("NEAR 1,5"),
+            Ok(NearToken::from_millinear(1500))
+        );
+        assert_eq!(
+            locale.parse("milliNEAR1500"),
+            Ok(NearToken::from_millinear(1500))
+        );
+        assert_eq!(locale.parse("1,5 NEAR"), Err(ParseError::MissingUnit));
+        assert_eq!(
+            locale.parse("NEAR 1,5 NEAR"),
+            Err(ParseError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn custom_group_separator_round_trip() {
+        for separator in ['~', '\u{66c}', '\u{2d9}', '\u{1d11e}'] {
+            let locale = Locale::new()
+                .decimal_separator(DecimalSeparator::Comma)
+                .group_separator(Some(separator))
+                .grouping(3, 2);
+            for tokens in [
+                NearToken::from_near(1_234_567),
+                NearToken::from_millinear(1_234_560),
+                NearToken::from_near(12),
+            ] {
+                let formatted = locale.format(tokens).to_string();
+                assert_eq!(locale.parse(&formatted), Ok(tokens), "{}", formatted);
+            }
+            assert_eq!(
+                locale.parse(&format!("12{}34{}567 NEAR", separator, separator)),
+                Ok(NearToken::from_near(1_234_567))
+            );
+            assert_eq!(
+                locale.parse(&format!("1{}234{}567 NEAR", separator, separator)),
+                Err(ParseError::InvalidDigitGrouping)
+            );
+            assert_eq!(
+                locale.parse("12,34,567 NEAR"),
+                Err(ParseError::InvalidNumber)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "digit group sizes must not be zero")]
+    fn zero_grouping() {
+        let _ = Locale::new().grouping(3, 0);
+    }
+}
@@ -0,0 +1,1578 @@
+//! Parser of `NearToken` amounts that can be evaluated at compile time.
+//!
+//! The grammar is an amount with an optional fractional part and an optional exponent
+//! followed by a case-insensitive unit, all surrounded by optional whitespace. `FromStr for NearToken` is implemented on top of it.
+//! The units are the names and aliases of [`NearUnit`]s.
+
+use crate::{
+    DecimalSeparator, Locale, NearToken, NearUnit, ParseDiagnostic, ParseError, ParseErrorKind,
+    Rounding, UnitPlacement,
+};
+
+/// The `Ⓝ` symbol, the only unit spelling that is not made of ASCII letters.
+const NEAR_SYMBOL: &[u8] = "Ⓝ".as_bytes();
+
+/// Character that may be used to group digits of an amount when parsing it with [`ParseOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupSeparator {
+    /// `_`, e.g. `1_000_000 NEAR`
+    Underscore,
+    /// `,`, e.g. `1,000,000 NEAR`
+    Comma,
+    /// Regular space or no-break space (U+00A0), e.g. `1 000 000 NEAR`
+    Space,
+    /// Thin space (U+2009) or narrow no-break space (U+202F), e.g. `1\u{2009}000\u{2009}000 NEAR`
+    ThinSpace,
+    /// Apostrophe or right single quotation mark (U+2019), e.g. `1'000'000 NEAR`
+    Apostrophe,
+    /// `.`, e.g. `1.000.000 NEAR`, only usable with [`DecimalSeparator::Comma`]
+    Period,
+}
+
+impl GroupSeparator {
+    /// Every separator supported by the parser.
+    pub const ALL: [GroupSeparator; 6] = [
+        GroupSeparator::Underscore,
+        GroupSeparator::Comma,
+        GroupSeparator::Space,
+        GroupSeparator::ThinSpace,
+        GroupSeparator::Apostrophe,
+        GroupSeparator::Period,
+    ];
+
+    /// Returns the separator that accepts `c`, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::GroupSeparator;
+    /// assert_eq!(GroupSeparator::from_char('\u{202f}'), Some(GroupSeparator::ThinSpace));
+    /// assert_eq!(GroupSeparator::from_char('-'), None);
+    /// ```
+    pub const fn from_char(c: char) -> Option<GroupSeparator> {
+        match c {
+            '_' => Some(GroupSeparator::Underscore),
+            ',' => Some(GroupSeparator::Comma),
+            ' ' | '\u{a0}' => Some(GroupSeparator::Space),
+            '\u{2009}' | '\u{202f}' => Some(GroupSeparator::ThinSpace),
+            '\'' | '\u{2019}' => Some(GroupSeparator::Apostrophe),
+            '.' => Some(GroupSeparator::Period),
+            _ => None,
+        }
+    }
+
+    /// UTF-8 encodings of the characters accepted for this separator.
+    const fn encodings(self) -> [&'static [u8]; 2] {
+        match self {
+            GroupSeparator::Underscore => [b"_", b"_"],
+            GroupSeparator::Comma => [b",", b","],
+            GroupSeparator::Space => [b" ", "\u{a0}".as_bytes()],
+            GroupSeparator::ThinSpace => ["\u{2009}".as_bytes(), "\u{202f}".as_bytes()],
+            GroupSeparator::Apostrophe => [b"'", "\u{2019}".as_bytes()],
+            GroupSeparator::Period => [b".", b"."],
+        }
+    }
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// UTF-8 encoding of a group separator of a [`Locale`] that is none of the [`GroupSeparator`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CustomSeparator {
+    None,
+    One([u8; 1]),
+    Two([u8; 2]),
+    Three([u8; 3]),
+    Four([u8; 4]),
+}
+
+impl CustomSeparator {
+    const fn from_char(c: char) -> Self {
+        /// Continuation byte with the 6 bits of `c` starting at `shift`.
+        const fn tail(c: u32, shift: u32) -> u8 {
+            0x80 | (c >> shift & 0x3f) as u8
+        }
+        let c = c as u32;
+        if c < 0x80 {
+            CustomSeparator::One([c as u8])
+        } else if c < 0x800 {
+            CustomSeparator::Two([0xc0 | (c >> 6) as u8, tail(c, 0)])
+        } else if c < 0x1_0000 {
+            CustomSeparator::Three([0xe0 | (c >> 12) as u8, tail(c, 6), tail(c, 0)])
+        } else {
+            CustomSeparator::Four([0xf0 | (c >> 18) as u8, tail(c, 12), tail(c, 6), tail(c, 0)])
+        }
+    }
+
+    const fn as_bytes(&self) -> &[u8] {
+        match self {
+            CustomSeparator::None => b"",
+            CustomSeparator::One(bytes) => bytes,
+            CustomSeparator::Two(bytes) => bytes,
+            CustomSeparator::Three(bytes) => bytes,
+            CustomSeparator::Four(bytes) => bytes,
+        }
+    }
+}
+
+/// Options of [`NearToken::parse_with`] that relax or adjust the strict syntax of `NearToken::from_str`.
+///
+/// Digit group separators are not accepted by default. When they are enabled, a number may use only one
+/// of the separators, the whole part must be grouped by three digits from the decimal point (the first group
+/// may be shorter), and the fractional part must be grouped by three digits from the decimal point (the last group
+/// may be shorter), so that `1,000.5` or `0.000_001` are accepted while `1,00,000` or `1__000` are rejected.
+/// The group sizes of the whole part can be changed with [`ParseOptions::grouping`].
+///
+/// The decimal separator, the digit grouping and the unit placement can also be taken from a [`Locale`].
+///
+/// Lenient inputs can be accepted as well: amounts without a unit with [`ParseOptions::default_unit`],
+/// numbers like `.5` or `5.` with [`ParseOptions::lenient_decimal_point`], and amounts more precise than
+/// the unit allows with [`ParseOptions::round_excess_digits`]. Any whitespace, or none at all,
+/// is accepted between the number and the unit regardless of the options.
+///
+/// # Examples
+/// ```
+/// use near_token::{GroupSeparator, NearToken, ParseError, ParseOptions};
+///
+/// let options = ParseOptions::new()
+///     .group_separator(GroupSeparator::Comma)
+///     .group_separator(GroupSeparator::Underscore);
+/// assert_eq!(NearToken::parse_with("1,000.5 NEAR", &options), Ok(NearToken::from_millinear(1_000_500)));
+/// assert_eq!(NearToken::parse_with("1_000 NEAR", &options), Ok(NearToken::from_near(1_000)));
+/// assert_eq!(NearToken::parse_with("1,00 NEAR", &options), Err(ParseError::InvalidDigitGrouping));
+/// assert_eq!(NearToken::parse_with("1 000 NEAR", &options), Err(ParseError::InvalidNumber));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    group_separators: u8,
+    custom_group_separator: CustomSeparator,
+    decimal_separator: DecimalSeparator,
+    primary_group: u8,
+    secondary_group: u8,
+    unit_placement: UnitPlacement,
+    default_unit: Option<NearUnit>,
+    lenient_decimal_point: bool,
+    excess_digits_rounding: Option<Rounding>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseOptions {
+    /// Options that accept exactly the same syntax as `NearToken::from_str`.
+    pub const fn new() -> Self {
+        Self {
+            group_separators: 0,
+            custom_group_separator: CustomSeparator::None,
+            decimal_separator: DecimalSeparator::Point,
+            primary_group: 3,
+            secondary_group: 3,
+            unit_placement: UnitPlacement::Suffix,
+            default_unit: None,
+            lenient_decimal_point: false,
+            excess_digits_rounding: None,
+        }
+    }
+
+    /// Sets the unit of amounts given without one, e.g. `100` (disabled by default).
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, NearUnit, ParseError, ParseOptions};
+    /// let options = ParseOptions::new().default_unit(Some(NearUnit::Near));
+    /// assert_eq!(NearToken::parse_with("100", &options), Ok(NearToken::from_near(100)));
+    /// assert_eq!(NearToken::parse_with("100 milliNEAR", &options), Ok(NearToken::from_millinear(100)));
+    /// assert_eq!(NearToken::parse_with("100", &ParseOptions::new()), Err(ParseError::MissingUnit));
+    /// ```
+    pub const fn default_unit(mut self, unit: Option<NearUnit>) -> Self {
+        self.default_unit = unit;
+        self
+    }
+
+    /// Allows either the integer or the fractional part next to the decimal separator to be omitted,
+    /// e.g. `.5 NEAR` or `5. NEAR` (disabled by default).
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, ParseError, ParseOptions};
+    /// let options = ParseOptions::new().lenient_decimal_point(true);
+    /// assert_eq!(NearToken::parse_with(".055 ynear", &options), Err(ParseError::LongFractional));
+    /// assert_eq!(NearToken::parse_with(".5 NEAR", &options), Ok(NearToken::from_millinear(500)));
+    /// assert_eq!(NearToken::parse_with("5. NEAR", &options), Ok(NearToken::from_near(5)));
+    /// assert_eq!(NearToken::parse_with(". NEAR", &options), Err(ParseError::InvalidNumber));
+    /// ```
+    pub const fn lenient_decimal_point(mut self, lenient: bool) -> Self {
+        self.lenient_decimal_point = lenient;
+        self
+    }
+
+    /// Rounds amounts more precise than 1 yoctoNEAR with `rounding` instead of rejecting them
+    /// with [`ParseError::LongFractional`] (disabled by default). Use [`Rounding::Down`] to truncate the excess digits.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, ParseOptions, Rounding};
+    /// let options = ParseOptions::new().round_excess_digits(Some(Rounding::HalfUp));
+    /// assert_eq!(NearToken::parse_with("2.5 yoctoNEAR", &options), Ok(NearToken::from_yoctonear(3)));
+    /// assert_eq!(NearToken::parse_with("1.5e-24 NEAR", &options), Ok(NearToken::from_yoctonear(2)));
+    /// ```
+    pub const fn round_excess_digits(mut self, rounding: Option<Rounding>) -> Self {
+        self.excess_digits_rounding = rounding;
+        self
+    }
+
+    /// Allows `separator` to be used for grouping digits.
+    pub const fn group_separator(mut self, separator: GroupSeparator) -> Self {
+        self.group_separators |= separator.bit();
+        self
+    }
+
+    /// Allows any of the [`GroupSeparator`]s to be used for grouping digits,
+    /// except the one that is the decimal separator.
+    pub const fn any_group_separator(mut self) -> Self {
+        let mut i = 0;
+        while i < GroupSeparator::ALL.len() {
+            self = self.group_separator(GroupSeparator::ALL[i]);
+            i += 1;
+        }
+        self
+    }
+
+    /// Sets the separator between the integer and the fractional parts (`DecimalSeparator::Point` by default).
+    pub const fn decimal_separator(mut self, separator: DecimalSeparator) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the sizes of digit groups in the whole part: the group nearest to the decimal separator
+    /// has `primary` digits, and the other ones have `secondary` digits, except the first one that may be shorter
+    /// (3 and 3 by default).
+    ///
+    /// # Panics
+    /// Panics if any of the sizes is zero.
+    pub const fn grouping(mut self, primary: u8, secondary: u8) -> Self {
+        assert!(
+            primary != 0 && secondary != 0,
+            "digit group sizes must not be zero"
+        );
+        self.primary_group = primary;
+        self.secondary_group = secondary;
+        self
+    }
+
+    /// Sets whether the unit is expected before or after the number (`UnitPlacement::Suffix` by default).
+    pub const fn unit_placement(mut self, placement: UnitPlacement) -> Self {
+        self.unit_placement = placement;
+        self
+    }
+
+    /// Applies the decimal separator, digit grouping and unit placement of `locale`,
+    /// and allows its group separator.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{Locale, NearToken, ParseOptions};
+    /// let options = ParseOptions::new().locale(&Locale::EN_IN);
+    /// assert_eq!(NearToken::parse_with("12,34,567.5 NEAR", &options), Ok(NearToken::from_millinear(1_234_567_500)));
+    /// ```
+    pub const fn locale(self, locale: &Locale) -> Self {
+        let options = self
+            .decimal_separator(locale.decimal_separator)
+            .grouping(locale.primary_group, locale.secondary_group)
+            .unit_placement(locale.unit_placement);
+        match locale.group_separator {
+            Some(separator) => match GroupSeparator::from_char(separator) {
+                Some(separator) => options.group_separator(separator),
+                // Any other separator can still be formatted, so it is accepted as it is.
+                None => ParseOptions {
+                    custom_group_separator: CustomSeparator::from_char(separator),
+                    ..options
+                },
+            },
+            None => options,
+        }
+    }
+
+    /// Returns the encoding of the first allowed group separator in `bytes[start..end]`,
+    /// or an empty slice if the number contains none.
+    const fn find_group_separator(&self, bytes: &[u8], start: usize, end: usize) -> &[u8] {
+        let mut i = start;
+        while i < end {
+            if !bytes[i].is_ascii_digit() && bytes[i] != self.decimal_separator.as_byte() {
+                let custom = self.custom_group_separator.as_bytes();
+                if starts_with(bytes, i, end, custom) {
+                    return custom;
+                }
+                let mut s = 0;
+                while s < GroupSeparator::ALL.len() {
+                    let separator = GroupSeparator::ALL[s];
+                    s += 1;
+                    if self.group_separators & separator.bit() == 0 {
+                        continue;
+                    }
+                    let encodings = separator.encodings();
+                    let mut e = 0;
+                    while e < encodings.len() {
+                        if starts_with(bytes, i, end, encodings[e]) {
+                            return encodings[e];
+                        }
+                        e += 1;
+                    }
+                }
+                return b"";
+            }
+            i += 1;
+        }
+        b""
+    }
+}
+
+impl NearToken {
+    /// `parse_const` parses an amount such as `"1.25 NEAR"` with the same syntax as `NearToken::from_str`,
+    /// but can be used in `const` context and never allocates.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, ParseError};
+    ///
+    /// const MIN_STORAGE: NearToken = match NearToken::parse_const("0.00125 NEAR") {
+    ///     Ok(tokens) => tokens,
+    ///     Err(err) => panic!("{}", err.as_str()),
+    /// };
+    /// assert_eq!(MIN_STORAGE, NearToken::from_micronear(1250));
+    /// assert_eq!(NearToken::parse_const("2.5e-3 NEAR"), Ok(NearToken::from_micronear(2500)));
+    /// assert_eq!(NearToken::parse_const("1.5 yN"), Err(ParseError::LongFractional));
+    /// assert_eq!(NearToken::parse_const("1e40 NEAR"), Err(ParseError::ExponentOverflow));
+    /// assert_eq!(NearToken::parse_const("1.5 UAH"), Err(ParseError::InvalidUnit));
+    /// ```
+    pub const fn parse_const(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with(s, &ParseOptions::new())
+    }
+
+    /// `parse_with` parses an amount such as `"1,000.5 NEAR"` with the syntax relaxed by `options`.
+    /// It can be used in `const` context and never allocates.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().any_group_separator();
+    /// assert_eq!(NearToken::parse_with("1 000 NEAR", &options), Ok(NearToken::from_near(1_000)));
+    /// assert_eq!(NearToken::parse_with("1'000 NEAR", &options), Ok(NearToken::from_near(1_000)));
+    /// assert_eq!(NearToken::parse_with("0.000_001 NEAR", &options), Ok(NearToken::from_micronear(1)));
+    /// ```
+    pub const fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        match parse_amount(s, options) {
+            Ok((tokens, _)) => Ok(tokens),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses `s` with the same syntax as `NearToken::from_str`, but rounds amounts more precise than 1 yoctoNEAR
+    /// with `rounding` instead of rejecting them, returning also whether the amount was rounded.
+    ///
+    /// Fractional parts of any length are accepted, in exponent notation as well.
+    /// See [`ParseOptions::round_excess_digits`] to round amounts with other parse options.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, Rounding};
+    ///
+    /// let price = "0.123456789012345678901234567890123 NEAR";
+    /// assert_eq!(
+    ///     NearToken::parse_rounded(price, Rounding::Down),
+    ///     Ok((NearToken::from_yoctonear(123_456_789_012_345_678_901_234), true))
+    /// );
+    /// assert_eq!(
+    ///     NearToken::parse_rounded(price, Rounding::HalfUp),
+    ///     Ok((NearToken::from_yoctonear(123_456_789_012_345_678_901_235), true))
+    /// );
+    /// assert_eq!(
+    ///     NearToken::parse_rounded("1.5 NEAR", Rounding::Up),
+    ///     Ok((NearToken::from_millinear(1_500), false))
+    /// );
+    /// ```
+    pub const fn parse_rounded(s: &str, rounding: Rounding) -> Result<(Self, bool), ParseError> {
+        parse_amount(s, &ParseOptions::new().round_excess_digits(Some(rounding)))
+    }
+
+    /// Parses `s` like [`NearToken::parse_with`], but returns an error that points at the offending part
+    /// of the input and suggests the intended unit for unit typos, e.g. to render caret-style diagnostics.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, ParseErrorKind, ParseOptions};
+    ///
+    /// let err = NearToken::parse_with_details("1e40 NEAR", &ParseOptions::new()).unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::Overflow);
+    /// assert_eq!(err.span(), 0..4);
+    ///
+    /// let err = NearToken::parse_with_details("0.1234 yoctoNEAR", &ParseOptions::new()).unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::TooManyFractionalDigits { max: 0 });
+    /// assert_eq!(err.span(), 2..6);
+    /// ```
+    pub fn parse_with_details(s: &str, options: &ParseOptions) -> Result<Self, ParseDiagnostic> {
+        match Self::parse_with(s, options) {
+            Ok(tokens) => Ok(tokens),
+            Err(err) => Err(diagnose(s, options, err)),
+        }
+    }
+}
+
+/// Parses the amount in `s` with `options`, returning also whether it was rounded.
+const fn parse_amount(s: &str, options: &ParseOptions) -> Result<(NearToken, bool), ParseError> {
+    let bytes = s.as_bytes();
+    let (number_start, number_end, unit_start, unit_end) = split_amount(bytes, options);
+    let multiplier = if unit_start == unit_end {
+        match options.default_unit {
+            Some(unit) => unit.multiplier(),
+            None => return Err(ParseError::MissingUnit),
+        }
+    } else {
+        match unit_multiplier(bytes, unit_start, unit_end) {
+            Some(multiplier) => multiplier,
+            None => return Err(ParseError::InvalidUnit),
+        }
+    };
+    match parse_decimal(bytes, number_start, number_end, multiplier, options) {
+        Ok((yoctonear, inexact)) => Ok((NearToken::from_yoctonear(yoctonear), inexact)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Splits the trimmed amount in `bytes` into the number and the unit according to the unit placement,
+/// returning `(number_start, number_end, unit_start, unit_end)`. The unit is empty if it is missing.
+const fn split_amount(bytes: &[u8], options: &ParseOptions) -> (usize, usize, usize, usize) {
+    let (start, end) = trim(bytes, 0, bytes.len());
+    match options.unit_placement {
+        UnitPlacement::Suffix => {
+            let unit_start = find_unit(bytes, start, end);
+            (start, unit_start, unit_start, end)
+        }
+        UnitPlacement::Prefix => {
+            let mut unit_end = start;
+            if starts_with(bytes, start, end, NEAR_SYMBOL) {
+                unit_end += NEAR_SYMBOL.len();
+            }
+            while unit_end < end && bytes[unit_end].is_ascii_alphabetic() {
+                unit_end += 1;
+            }
+            (unit_end, end, start, unit_end)
+        }
+    }
+}
+
+/// Locates the part of `s` that caused `err` when parsing it with `options`.
+fn diagnose(s: &str, options: &ParseOptions, err: ParseError) -> ParseDiagnostic {
+    let bytes = s.as_bytes();
+    let (number_start, number_end, unit_start, unit_end) = split_amount(bytes, options);
+    let (number_start, number_end) = trim(bytes, number_start, number_end);
+    match err {
+        ParseError::MissingUnit => {
+            ParseDiagnostic::new(ParseErrorKind::MissingUnit, unit_start, unit_end)
+        }
+        ParseError::InvalidUnit => {
+            ParseDiagnostic::new(ParseErrorKind::UnknownUnit, unit_start, unit_end)
+                .with_suggestion(NearUnit::closest(&s[unit_start..unit_end]))
+        }
+        ParseError::InvalidNumber => {
+            let mut digits_start = number_start;
+            if digits_start < number_end && bytes[digits_start] == b'+' {
+                digits_start += 1;
+            }
+            if digits_start == number_end
+                || bytes[digits_start] == options.decimal_separator.as_byte()
+            {
+                ParseDiagnostic::new(ParseErrorKind::EmptyInteger, digits_start, digits_start)
+            } else {
+                ParseDiagnostic::new(ParseErrorKind::InvalidNumber, number_start, number_end)
+            }
+        }
+        ParseError::InvalidDigitGrouping => ParseDiagnostic::new(
+            ParseErrorKind::InvalidDigitGrouping,
+            number_start,
+            number_end,
+        ),
+        ParseError::LongWhole => {
+            let decimal = options.decimal_separator.as_byte();
+            let whole_end = match bytes[number_start..number_end]
+                .iter()
+                .position(|&b| b == decimal)
+            {
+                Some(dot) => number_start + dot,
+                None => number_end,
+            };
+            ParseDiagnostic::new(ParseErrorKind::Overflow, number_start, whole_end)
+        }
+        ParseError::ExponentOverflow => {
+            ParseDiagnostic::new(ParseErrorKind::Overflow, number_start, number_end)
+        }
+        ParseError::LongFractional => {
+            let max = match NearUnit::find(bytes, unit_start, unit_end).or(options.default_unit) {
+                Some(unit) => unit.decimals(),
+                None => 0,
+            };
+            let kind = ParseErrorKind::TooManyFractionalDigits { max };
+            let number = &bytes[number_start..number_end];
+            let decimal = options.decimal_separator.as_byte();
+            let excess = match number.iter().position(|&b| b == decimal) {
+                // The excess digits of a number in exponent notation depend on the exponent,
+                // so the whole number is pointed at.
+                Some(dot) if !number.iter().any(|b| b.eq_ignore_ascii_case(&b'e')) => number
+                    [dot + 1..]
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, b)| b.is_ascii_digit())
+                    .nth(max as usize)
+                    .map(|(i, _)| number_start + dot + 1 + i),
+                _ => None,
+            };
+            ParseDiagnostic::new(kind, excess.unwrap_or(number_start), number_end)
+        }
+    }
+}
+
+/// Parses the decimal number in `bytes[start..end]`, surrounded by optional whitespace,
+/// and multiplies it by `multiplier` which must be a power of ten.
+///
+/// Returns also whether the result was rounded according to [`ParseOptions::round_excess_digits`].
+pub(crate) const fn parse_decimal(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    multiplier: u128,
+    options: &ParseOptions,
+) -> Result<(u128, bool), ParseError> {
+    let (start, end) = trim(bytes, start, end);
+    let mut exponent = start;
+    while exponent < end && !matches!(bytes[exponent], b'e' | b'E') {
+        exponent += 1;
+    }
+    let mut digits_start = start;
+    if digits_start < exponent && bytes[digits_start] == b'+' {
+        digits_start += 1;
+    }
+    let mut dot = digits_start;
+    while dot < exponent && bytes[dot] != options.decimal_separator.as_byte() {
+        dot += 1;
+    }
+
+    let separator = options.find_group_separator(bytes, digits_start, exponent);
+    let (primary, secondary) = (
+        options.primary_group as usize,
+        options.secondary_group as usize,
+    );
+    // With a lenient decimal point, one of the parts next to it may be empty, but not both.
+    let lenient = options.lenient_decimal_point && dot < exponent && digits_start + 1 < exponent;
+    if !(lenient && digits_start == dot) {
+        if let Err(err) =
+            check_digit_groups(bytes, digits_start, dot, separator, (primary, secondary))
+        {
+            return Err(err);
+        }
+    }
+    if dot < exponent && !(lenient && dot + 1 == exponent) {
+        if let Err(err) = check_digit_groups(bytes, dot + 1, exponent, separator, (3, 0)) {
+            return Err(err);
+        }
+    }
+    if exponent < end {
+        return parse_scientific(bytes, digits_start, dot, exponent, end, multiplier, options);
+    }
+
+    // The digits are already validated, so only an overflow is possible here.
+    let Some(whole) = parse_digits(bytes, digits_start, dot, separator) else {
+        return Err(ParseError::LongWhole);
+    };
+    let mut fractional = 0;
+    // Position of the first fractional digit that is more precise than 1 yoctoNEAR.
+    let mut excess = end;
+    if dot < end {
+        let mut step = multiplier;
+        let mut i = dot + 1;
+        while i < end {
+            if starts_with(bytes, i, end, separator) {
+                i += separator.len();
+                continue;
+            }
+            if step == 1 {
+                break;
+            }
+            step /= 10;
+            i += 1;
+        }
+        excess = i;
+        // The fractional part is shorter than the number of digits in `multiplier`, so it always fits into u128.
+        let Some(digits) = parse_digits(bytes, dot + 1, excess, separator) else {
+            return Err(ParseError::InvalidNumber);
+        };
+        fractional = digits * step;
+    }
+
+    let value = match whole.checked_mul(multiplier) {
+        Some(whole) => match whole.checked_add(fractional) {
+            Some(value) => value,
+            None => return Err(ParseError::LongWhole),
+        },
+        None => return Err(ParseError::LongWhole),
+    };
+    if excess == end {
+        return Ok((value, false));
+    }
+    let Some(rounding) = options.excess_digits_rounding else {
+        return Err(ParseError::LongFractional);
+    };
+    // Only the first excess digit and whether any of the following ones is non-zero matter for rounding,
+    // so the excess digits `d...` are rounded as the fraction `(2 * d + sticky) / 20`.
+    let mut sticky = 0;
+    let mut i = excess + 1;
+    while i < end {
+        if matches!(bytes[i], b'1'..=b'9') {
+            sticky = 1;
+        }
+        i += 1;
+    }
+    let remainder = (bytes[excess] - b'0') as u128 * 2 + sticky;
+    match rounding.round_quotient(value, remainder, 20) {
+        Some(rounded) => Ok((rounded, remainder != 0)),
+        None => Err(ParseError::LongWhole),
+    }
+}
+
+/// Parses the number `bytes[start..end]` in exponent notation, e.g. `2.5e-3`, where `dot` and `exponent`
+/// are the positions of the decimal point (or `exponent` if there is none) and of the exponent marker.
+/// The digits before the exponent marker must be already validated.
+///
+/// Unlike plain decimal numbers, trailing zeros are insignificant here, so `1.0e0 yoctoNEAR` is
+/// exactly one yoctoNEAR while `1.5e0 yoctoNEAR` cannot be represented.
+const fn parse_scientific(
+    bytes: &[u8],
+    start: usize,
+    dot: usize,
+    exponent: usize,
+    end: usize,
+    multiplier: u128,
+    options: &ParseOptions,
+) -> Result<(u128, bool), ParseError> {
+    let separator = options.find_group_separator(bytes, start, exponent);
+    let mut exponent_start = exponent + 1;
+    let negative_exponent = exponent_start < end && bytes[exponent_start] == b'-';
+    if exponent_start < end && matches!(bytes[exponent_start], b'+' | b'-') {
+        exponent_start += 1;
+    }
+    if !is_digits(bytes, exponent_start, end) {
+        return Err(ParseError::InvalidNumber);
+    }
+    // Any exponent beyond this limit overflows or loses precision anyway,
+    // saturating it keeps the arithmetic below in range.
+    const EXPONENT_LIMIT: i64 = 1_000;
+    let mut exponent_value: i64 = 0;
+    let mut i = exponent_start;
+    while i < end {
+        if exponent_value < EXPONENT_LIMIT {
+            exponent_value = exponent_value * 10 + (bytes[i] - b'0') as i64;
+        }
+        i += 1;
+    }
+    if negative_exponent {
+        exponent_value = -exponent_value;
+    }
+
+    // The amount is `significand * 10^scale` yoctoNEAR, where the significand is made of all the digits
+    // of the number without its trailing zeros.
+    let mut scale = exponent_value;
+    let mut unit = multiplier;
+    while unit >= 10 {
+        unit /= 10;
+        scale += 1;
+    }
+    let mut significand: u128 = 0;
+    let mut pending_zeros: i64 = 0;
+    // Digits that do not fit into the significand only matter for rounding, so just the first of them
+    // and whether any of the following ones is non-zero are kept.
+    let mut truncated = false;
+    let mut first_dropped: u128 = 0;
+    let mut sticky = false;
+    let mut i = start;
+    while i < exponent {
+        if starts_with(bytes, i, exponent, separator) {
+            i += separator.len();
+            continue;
+        }
+        if i != dot {
+            if i > dot {
+                scale -= 1;
+            }
+            let digit = (bytes[i] - b'0') as u128;
+            if digit == 0 && !truncated {
+                pending_zeros += 1;
+            } else {
+                // The pending zeros are followed by a non-zero digit, so they are significant.
+                let mut remaining = pending_zeros;
+                pending_zeros = 0;
+                while remaining >= 0 {
+                    let next = if remaining > 0 { 0 } else { digit };
+                    remaining -= 1;
+                    if truncated {
+                        scale += 1;
+                        sticky = sticky || next != 0;
+                    } else if let Some(value) = push_digit(significand, next) {
+                        significand = value;
+                    } else {
+                        truncated = true;
+                        first_dropped = next;
+                        scale += 1;
+                    }
+                }
+            }
+        }
+        i += 1;
+    }
+    if significand == 0 {
+        return Ok((0, false));
+    }
+    // Trailing zeros are not part of the significand.
+    scale += pending_zeros;
+
+    if scale < 0 || truncated {
+        // A truncated significand is at least `u128::MAX / 10`, so any dropped digit before
+        // the yoctoNEAR one makes the amount overflow.
+        if scale > 0 {
+            return Err(ParseError::ExponentOverflow);
+        }
+        // The significand does not end with zero and the dropped digits are not all zeros,
+        // so the amount is more precise than 1 yoctoNEAR.
+        let Some(rounding) = options.excess_digits_rounding else {
+            return Err(ParseError::LongFractional);
+        };
+        // As in `parse_decimal`, the excess digits are rounded as the fraction `(2 * d + sticky) / 20`,
+        // where `d` is the first excess digit.
+        let precision = (-scale) as u32;
+        let (quotient, first, rest) = if precision == 0 {
+            (significand, first_dropped, sticky)
+        } else {
+            match 10u128.checked_pow(precision - 1) {
+                Some(power) => {
+                    let head = significand / power;
+                    (
+                        head / 10,
+                        head % 10,
+                        significand % power != 0 || first_dropped != 0 || sticky,
+                    )
+                }
+                // `10^39` does not fit into u128, but any significand is less than half of it.
+                None => (0, 0, true),
+            }
+        };
+        let remainder = first * 2 + rest as u128;
+        return match rounding.round_quotient(quotient, remainder, 20) {
+            Some(value) => Ok((value, remainder != 0)),
+            None => Err(ParseError::ExponentOverflow),
+        };
+    }
+    if scale > u32::MAX as i64 {
+        return Err(ParseError::ExponentOverflow);
+    }
+    match 10u128.checked_pow(scale as u32) {
+        Some(power) => match significand.checked_mul(power) {
+            Some(value) => Ok((value, false)),
+            None => Err(ParseError::ExponentOverflow),
+        },
+        None => Err(ParseError::ExponentOverflow),
+    }
+}
+
+/// Appends `digit` to the decimal digits of `value`, returning None on overflow.
+const fn push_digit(value: u128, digit: u128) -> Option<u128> {
+    match value.checked_mul(10) {
+        Some(value) => value.checked_add(digit),
+        None => None,
+    }
+}
+
+/// Checks that `bytes[start..end]` is a non-empty sequence of decimal digits, optionally grouped with `separator`.
+///
+/// `(primary, secondary)` are the group sizes of the whole part of a number, where the last group has `primary` digits,
+/// the preceding ones have `secondary` digits, and only the first one may be shorter.
+/// The fractional part is described by `(3, 0)`: all the groups have three digits, and only the last one may be shorter.
+const fn check_digit_groups(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    separator: &[u8],
+    (primary, secondary): (usize, usize),
+) -> Result<(), ParseError> {
+    if start == end {
+        return Err(ParseError::InvalidNumber);
+    }
+    let fractional = secondary == 0;
+    let mut groups = 0;
+    let mut group_len = 0;
+    let mut i = start;
+    while i < end {
+        if starts_with(bytes, i, end, separator) {
+            let valid = if fractional {
+                group_len == primary
+            } else if groups == 0 {
+                group_len >= 1 && group_len <= secondary
+            } else {
+                group_len == secondary
+            };
+            if !valid {
+                return Err(ParseError::InvalidDigitGrouping);
+            }
+            groups += 1;
+            group_len = 0;
+            i += separator.len();
+            continue;
+        }
+        if !bytes[i].is_ascii_digit() {
+            return Err(ParseError::InvalidNumber);
+        }
+        group_len += 1;
+        i += 1;
+    }
+    let valid = if groups == 0 {
+        true
+    } else if fractional {
+        group_len >= 1 && group_len <= primary
+    } else {
+        group_len == primary
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(ParseError::InvalidDigitGrouping)
+    }
+}
+
+/// Checks whether `bytes[i..end]` starts with the non-empty `prefix`.
+const fn starts_with(bytes: &[u8], i: usize, end: usize, prefix: &[u8]) -> bool {
+    if prefix.is_empty() || end - i < prefix.len() {
+        return false;
+    }
+    let mut j = 0;
+    while j < prefix.len() {
+        if bytes[i + j] != prefix[j] {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+/// Returns the position of the first letter of the unit in `bytes[start..end]`, or `end` if there is none.
+///
+/// The exponent marker of a number in exponent notation, e.g. `e` in `1e24 yoctoNEAR`, is not a part of the unit.
+pub(crate) const fn find_unit(bytes: &[u8], start: usize, end: usize) -> usize {
+    let mut i = start;
+    while i < end {
+        if (bytes[i].is_ascii_alphabetic() && !is_exponent_marker(bytes, start, i, end))
+            || starts_with(bytes, i, end, NEAR_SYMBOL)
+        {
+            return i;
+        }
+        i += 1;
+    }
+    end
+}
+
+/// Checks whether `bytes[i]` is `e` or `E` that follows a digit or a decimal point
+/// and is followed by digits with an optional sign.
+const fn is_exponent_marker(bytes: &[u8], start: usize, i: usize, end: usize) -> bool {
+    if !matches!(bytes[i], b'e' | b'E') || i == start || !matches!(bytes[i - 1], b'0'..=b'9' | b'.')
+    {
+        return false;
+    }
+    let mut next = i + 1;
+    if next < end && matches!(bytes[next], b'+' | b'-') {
+        next += 1;
+    }
+    next < end && bytes[next].is_ascii_digit()
+}
+
+/// Returns the multiplier of the unit spelled by `bytes[start..end]` in any letter case.
+const fn unit_multiplier(bytes: &[u8], start: usize, end: usize) -> Option<u128> {
+    match NearUnit::find(bytes, start, end) {
+        Some(unit) => Some(unit.multiplier()),
+        None => None,
+    }
+}
+
+/// Parses the digits in `bytes[start..end]`, skipping `separator`, returning None if the value overflows `u128`.
+/// The digits must be already validated.
+const fn parse_digits(
+    bytes: &[u8],
+    mut start: usize,
+    end: usize,
+    separator: &[u8],
+) -> Option<u128> {
+    let mut value: u128 = 0;
+    while start < end {
+        if starts_with(bytes, start, end, separator) {
+            start += separator.len();
+            continue;
+        }
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[start] - b'0') as u128) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        start += 1;
+    }
+    Some(value)
+}
+
+/// Checks that `bytes[start..end]` is a non-empty sequence of decimal digits.
+const fn is_digits(bytes: &[u8], mut start: usize, end: usize) -> bool {
+    if start == end {
+        return false;
+    }
+    while start < end {
+        if !bytes[start].is_ascii_digit() {
+            return false;
+        }
+        start += 1;
+    }
+    true
+}
+
+/// Narrows `start..end` to exclude leading and trailing whitespace as defined by `char::is_whitespace`.
+const fn trim(bytes: &[u8], mut start: usize, mut end: usize) -> (usize, usize) {
+    loop {
+        let len = whitespace_len(bytes, start, end);
+        if len == 0 {
+            break;
+        }
+        start += len;
+    }
+    while end > start {
+        // Find the first byte of the last character, skipping UTF-8 continuation bytes.
+        let mut char_start = end - 1;
+        while char_start > start && bytes[char_start] & 0b1100_0000 == 0b1000_0000 {
+            char_start -= 1;
+        }
+        if whitespace_len(bytes, char_start, end) != end - char_start {
+            break;
+        }
+        end = char_start;
+    }
+    (start, end)
+}
+
+/// Returns the length in bytes of the whitespace character starting at `bytes[i]`, or 0 if there is none.
+///
+/// `char::is_whitespace` is not available in const context, so the Unicode `White_Space` property
+/// is matched on the UTF-8 encoding directly.
+const fn whitespace_len(bytes: &[u8], i: usize, end: usize) -> usize {
+    match (
+        byte_at(bytes, i, end),
+        byte_at(bytes, i + 1, end),
+        byte_at(bytes, i + 2, end),
+    ) {
+        (b'\t'..=b'\r' | b' ', _, _) => 1,
+        // U+0085, U+00A0
+        (0xC2, 0x85 | 0xA0, _) => 2,
+        // U+1680
+        (0xE1, 0x9A, 0x80) => 3,
+        // U+2000..=U+200A, U+2028, U+2029, U+202F
+        (0xE2, 0x80, 0x80..=0x8A | 0xA8 | 0xA9 | 0xAF) => 3,
+        // U+205F
+        (0xE2, 0x81, 0x9F) => 3,
+        // U+3000
+        (0xE3, 0x80, 0x80) => 3,
+        _ => 0,
+    }
+}
+
+/// Returns `bytes[i]` if it is before `end`, and 0 otherwise.
+const fn byte_at(bytes: &[u8], i: usize, end: usize) -> u8 {
+    if i < end {
+        bytes[i]
+    } else {
+        0
+    }
+}
+
+/// Creates a `NearToken` constant from a string literal with the same syntax as `NearToken::from_str`,
+/// checking it at compile time.
+///
+/// # Examples
+/// ```
+/// use near_token::{near, NearToken};
+///
+/// const STORAGE_DEPOSIT: NearToken = near!("1.25 NEAR");
+/// assert_eq!(STORAGE_DEPOSIT, NearToken::from_millinear(1250));
+/// assert_eq!(near!("100 yoctoNEAR"), NearToken::from_yoctonear(100));
+/// assert_eq!(near!("0.5 milliNEAR"), NearToken::from_micronear(500));
+/// ```
+///
+/// Malformed literals are rejected by the compiler:
+/// ```compile_fail
+/// let tokens = near_token::near!("1.25");
+/// ```
+/// ```compile_fail
+/// let tokens = near_token::near!("1.5 yoctoNEAR");
+/// ```
+/// ```compile_fail
+/// let tokens = near_token::near!("340282366920939 NEAR");
+/// ```
+#[macro_export]
+macro_rules! near {
+    ($amount:literal) => {{
+        const TOKENS: $crate::NearToken = match $crate::NearToken::parse_const($amount) {
+            ::core::result::Result::Ok(tokens) => tokens,
+            ::core::result::Result::Err(err) => ::core::panic!("{}", err.as_str()),
+        };
+        TOKENS
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GroupSeparator, NearToken, NearUnit, ParseError, ParseErrorKind, ParseOptions, Rounding,
+        UnitPlacement,
+    };
+
+    #[test]
+    fn near_macro() {
+        const DEPOSIT: NearToken = near!("1.25 NEAR");
+        assert_eq!(DEPOSIT, NearToken::from_millinear(1250));
+        assert_eq!(near!("  7 yN\t"), NearToken::from_yoctonear(7));
+        assert_eq!(near!("+3.000 MicroNear"), NearToken::from_micronear(3));
+        assert_eq!(
+            near!("340282366920938.463463374607431768211455 NEAR"),
+            NearToken::MAX
+        );
+    }
+
+    #[test]
+    fn parse_const() {
+        for (s, expected) in [
+            ("0 NEAR", NearToken::ZERO),
+            ("1.25 NEAR", NearToken::from_millinear(1250)),
+            ("1.25N", NearToken::from_millinear(1250)),
+            ("007 yoctonear", NearToken::from_yoctonear(7)),
+            ("+2.50 near", NearToken::from_millinear(2500)),
+            (
+                "0.000000000000000000000001 near",
+                NearToken::from_yoctonear(1),
+            ),
+            (
+                "\u{3000}1.5 milliNEAR\u{a0}",
+                NearToken::from_micronear(1500),
+            ),
+            ("\u{2028}2 microNEAR\u{205f}", NearToken::from_micronear(2)),
+            (
+                "340282366920938463463374607431768211455 yoctoNEAR",
+                NearToken::MAX,
+            ),
+            (
+                "340282366920938.463463374607431768211455 NEAR",
+                NearToken::MAX,
+            ),
+        ] {
+            assert_eq!(NearToken::parse_const(s), Ok(expected), "input: {:?}", s);
+        }
+    }
+
+    #[test]
+    fn parse_units() {
+        for unit in NearUnit::ALL {
+            for name in [unit.name()].iter().chain(unit.aliases()) {
+                assert_eq!(
+                    NearToken::parse_const(&format!("1 {}", name)),
+                    Ok(NearToken::from_unit(1, unit)),
+                    "unit: {}",
+                    name
+                );
+            }
+        }
+        for (s, expected) in [
+            ("2 kiloNEAR", NearToken::from_near(2_000)),
+            ("1.5 MEGANEAR", NearToken::from_near(1_500_000)),
+            ("0.0000001 megaNEAR", NearToken::from_millinear(100)),
+            ("3 nanonear", NearToken::from_yoctonear(3 * 10u128.pow(15))),
+            ("1 zeptoNEAR", NearToken::from_yoctonear(1_000)),
+            ("1.5 Ⓝ", NearToken::from_millinear(1_500)),
+            ("1.5Ⓝ", NearToken::from_millinear(1_500)),
+        ] {
+            assert_eq!(NearToken::parse_const(s), Ok(expected), "input: {:?}", s);
+        }
+        let prefix = ParseOptions::new().unit_placement(UnitPlacement::Prefix);
+        assert_eq!(
+            NearToken::parse_with("Ⓝ1.5", &prefix),
+            Ok(NearToken::from_millinear(1_500))
+        );
+        assert_eq!(
+            NearToken::parse_const("0.0001 zeptoNEAR"),
+            Err(ParseError::LongFractional)
+        );
+        assert_eq!(
+            NearToken::parse_const("1 ⓃEAR"),
+            Err(ParseError::InvalidUnit)
+        );
+        assert_eq!(
+            NearToken::parse_const("1000000000 megaNEAR"),
+            Err(ParseError::LongWhole)
+        );
+    }
+
+    #[test]
+    fn parse_with_details() {
+        let default = ParseOptions::new();
+        for (s, kind, span, suggestion) in [
+            ("1.5", ParseErrorKind::MissingUnit, 3..3, None),
+            (" 1.5  ", ParseErrorKind::MissingUnit, 4..4, None),
+            (
+                "1.5 NAER",
+                ParseErrorKind::UnknownUnit,
+                4..8,
+                Some(NearUnit::Near),
+            ),
+            (
+                "1.5 millinera ",
+                ParseErrorKind::UnknownUnit,
+                4..13,
+                Some(NearUnit::MilliNear),
+            ),
+            ("1.5 UAH", ParseErrorKind::UnknownUnit, 4..7, None),
+            (".5 NEAR", ParseErrorKind::EmptyInteger, 0..0, None),
+            ("+.5 NEAR", ParseErrorKind::EmptyInteger, 1..1, None),
+            ("1.2.3 NEAR", ParseErrorKind::InvalidNumber, 0..5, None),
+            (
+                "0.12345 yoctoNEAR",
+                ParseErrorKind::TooManyFractionalDigits { max: 0 },
+                2..7,
+                None,
+            ),
+            (
+                "1.0000000000000000000000001 NEAR",
+                ParseErrorKind::TooManyFractionalDigits { max: 24 },
+                26..27,
+                None,
+            ),
+            (
+                "1.5e-24 NEAR",
+                ParseErrorKind::TooManyFractionalDigits { max: 24 },
+                0..7,
+                None,
+            ),
+            ("1e40 NEAR", ParseErrorKind::Overflow, 0..4, None),
+            (
+                "340282366920939 NEAR",
+                ParseErrorKind::Overflow,
+                0..15,
+                None,
+            ),
+            (
+                "340282366920938463463374607431768211456 yoctoNEAR",
+                ParseErrorKind::Overflow,
+                0..39,
+                None,
+            ),
+            (
+                "340282366920939.5 NEAR",
+                ParseErrorKind::Overflow,
+                0..15,
+                None,
+            ),
+        ] {
+            let err = NearToken::parse_with_details(s, &default).unwrap_err();
+            assert_eq!(err.kind(), kind, "input: {:?}", s);
+            assert_eq!(err.span(), span, "input: {:?}", s);
+            assert_eq!(err.suggestion(), suggestion, "input: {:?}", s);
+        }
+
+        let options = ParseOptions::new().group_separator(GroupSeparator::Comma);
+        let err = NearToken::parse_with_details("1,00 NEAR", &options).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigitGrouping);
+        assert_eq!(err.span(), 0..4);
+
+        let prefix = ParseOptions::new().unit_placement(UnitPlacement::Prefix);
+        let err = NearToken::parse_with_details("NAER 1", &prefix).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err.span(), 0..4);
+        assert_eq!(
+            NearToken::parse_with_details("1.5 NEAR", &default),
+            Ok(NearToken::from_millinear(1_500))
+        );
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let options = ParseOptions::new()
+            .default_unit(Some(NearUnit::YoctoNear))
+            .lenient_decimal_point(true)
+            .round_excess_digits(Some(Rounding::Down));
+        for (s, expected) in [
+            ("100", NearToken::from_yoctonear(100)),
+            ("  100  ", NearToken::from_yoctonear(100)),
+            ("100.", NearToken::from_yoctonear(100)),
+            (".055 ynear", NearToken::ZERO),
+            ("+.5 NEAR", NearToken::from_millinear(500)),
+            (".5e1 NEAR", NearToken::from_near(5)),
+            ("5.e-1 NEAR", NearToken::from_millinear(500)),
+            ("1.5NEAR", NearToken::from_millinear(1_500)),
+            ("1.5 \t\u{a0} NEAR", NearToken::from_millinear(1_500)),
+            (
+                "1.9999 milliNEAR",
+                NearToken::from_yoctonear(19_999 * 10u128.pow(17)),
+            ),
+            ("1.5e-30 NEAR", NearToken::ZERO),
+            ("1.0000000000000000000000009 NEAR", NearToken::from_near(1)),
+        ] {
+            assert_eq!(
+                NearToken::parse_with(s, &options),
+                Ok(expected),
+                "input: {:?}",
+                s
+            );
+        }
+        for (s, expected) in [
+            (".", ParseError::InvalidNumber),
+            (". NEAR", ParseError::InvalidNumber),
+            ("+. NEAR", ParseError::InvalidNumber),
+            ("..5 NEAR", ParseError::InvalidNumber),
+            ("1 NAER", ParseError::InvalidUnit),
+        ] {
+            assert_eq!(
+                NearToken::parse_with(s, &options),
+                Err(expected),
+                "input: {:?}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn parse_rounding_excess_digits() {
+        for (rounding, s, expected) in [
+            (Rounding::Down, "2.5 yoctoNEAR", 2),
+            (Rounding::Up, "2.0001 yoctoNEAR", 3),
+            (Rounding::Up, "2.0000 yoctoNEAR", 2),
+            (Rounding::HalfUp, "2.5 yoctoNEAR", 3),
+            (Rounding::HalfUp, "2.4999 yoctoNEAR", 2),
+            (Rounding::HalfEven, "2.5 yoctoNEAR", 2),
+            (Rounding::HalfEven, "3.5 yoctoNEAR", 4),
+            (Rounding::HalfEven, "2.5001 yoctoNEAR", 3),
+            (Rounding::HalfEven, "25e-1 yoctoNEAR", 2),
+            (Rounding::HalfEven, "2501e-3 yoctoNEAR", 3),
+            (Rounding::Up, "1e-1000 NEAR", 1),
+            (Rounding::HalfUp, "1e-1000 NEAR", 0),
+        ] {
+            let options = ParseOptions::new().round_excess_digits(Some(rounding));
+            assert_eq!(
+                NearToken::parse_with(s, &options),
+                Ok(NearToken::from_yoctonear(expected)),
+                "input: {:?}, rounding: {:?}",
+                s,
+                rounding
+            );
+        }
+        let options = ParseOptions::new().round_excess_digits(Some(Rounding::Up));
+        assert_eq!(
+            NearToken::parse_with(
+                "340282366920938463463374607431768211455.1 yoctoNEAR",
+                &options
+            ),
+            Err(ParseError::LongWhole)
+        );
+        assert_eq!(
+            NearToken::parse_with("2.5 yoctoNEAR", &ParseOptions::new()),
+            Err(ParseError::LongFractional)
+        );
+    }
+
+    #[test]
+    fn parse_rounded() {
+        let long = format!("1.{}5 NEAR", "0".repeat(40));
+        let scientific = format!("1.{}5e0 NEAR", "0".repeat(40));
+        for (s, rounding, expected, inexact) in [
+            (
+                "1.5 NEAR",
+                Rounding::Down,
+                NearToken::from_millinear(1_500),
+                false,
+            ),
+            (
+                "1.0000 yoctoNEAR",
+                Rounding::Up,
+                NearToken::from_yoctonear(1),
+                false,
+            ),
+            (
+                "1.0001 yoctoNEAR",
+                Rounding::Down,
+                NearToken::from_yoctonear(1),
+                true,
+            ),
+            (
+                "1.0001 yoctoNEAR",
+                Rounding::Up,
+                NearToken::from_yoctonear(2),
+                true,
+            ),
+            ("0.4 yoctoNEAR", Rounding::HalfEven, NearToken::ZERO, true),
+            (
+                long.as_str(),
+                Rounding::Up,
+                NearToken::from_yoctonear(10u128.pow(24) + 1),
+                true,
+            ),
+            (
+                long.as_str(),
+                Rounding::HalfUp,
+                NearToken::from_near(1),
+                true,
+            ),
+            (
+                "15e-25 NEAR",
+                Rounding::HalfEven,
+                NearToken::from_yoctonear(2),
+                true,
+            ),
+            ("1e-1000 NEAR", Rounding::Down, NearToken::ZERO, true),
+            (
+                scientific.as_str(),
+                Rounding::Down,
+                NearToken::from_near(1),
+                true,
+            ),
+            (
+                scientific.as_str(),
+                Rounding::Up,
+                NearToken::from_yoctonear(10u128.pow(24) + 1),
+                true,
+            ),
+            (
+                "340282366920938463463374607431768211454.5000000000000000001e0 yoctoNEAR",
+                Rounding::HalfUp,
+                NearToken::MAX,
+                true,
+            ),
+            (
+                "3402823669209384634633746074317682114545e-1 yoctoNEAR",
+                Rounding::HalfEven,
+                NearToken::from_yoctonear(u128::MAX - 1),
+                true,
+            ),
+            (
+                "2e3 yoctoNEAR",
+                Rounding::Down,
+                NearToken::from_yoctonear(2_000),
+                false,
+            ),
+        ] {
+            assert_eq!(
+                NearToken::parse_rounded(s, rounding),
+                Ok((expected, inexact)),
+                "input: {:?}, rounding: {:?}",
+                s,
+                rounding
+            );
+        }
+        for (s, expected) in [
+            ("1.5", ParseError::MissingUnit),
+            ("1..5 NEAR", ParseError::InvalidNumber),
+            (
+                "340282366920938.4634633746074317682114559 NEAR",
+                ParseError::LongWhole,
+            ),
+            ("1e40 NEAR", ParseError::ExponentOverflow),
+            (
+                "340282366920938463463374607431768211455.1e0 yoctoNEAR",
+                ParseError::ExponentOverflow,
+            ),
+            (
+                "3402823669209384634633746074317682114551e0 yoctoNEAR",
+                ParseError::ExponentOverflow,
+            ),
+        ] {
+            assert_eq!(
+                NearToken::parse_rounded(s, Rounding::Up),
+                Err(expected),
+                "input: {:?}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn parse_scientific() {
+        for (s, expected) in [
+            ("1e24 yoctoNEAR", NearToken::from_near(1)),
+            ("1E24yN", NearToken::from_near(1)),
+            ("2.5e-3 NEAR", NearToken::from_micronear(2500)),
+            ("2.5E+3 NEAR", NearToken::from_near(2500)),
+            ("+25e-4 NEAR", NearToken::from_micronear(2500)),
+            ("0.025e2 milliNEAR", NearToken::from_micronear(2500)),
+            ("1.0e0 yoctoNEAR", NearToken::from_yoctonear(1)),
+            ("1.000e-24 NEAR", NearToken::from_yoctonear(1)),
+            ("100e-2 yoctoNEAR", NearToken::from_yoctonear(1)),
+            ("0e99999999999999999999 NEAR", NearToken::ZERO),
+            ("0.000e-50 NEAR", NearToken::ZERO),
+            (
+                "3.40282366920938463463374607431768211455e38 yoctoNEAR",
+                NearToken::MAX,
+            ),
+            (
+                "340282366920938463463374607431768211455000e-3 yoctoNEAR",
+                NearToken::MAX,
+            ),
+            ("3e0N", NearToken::from_near(3)),
+        ] {
+            assert_eq!(NearToken::parse_const(s), Ok(expected), "input: {:?}", s);
+        }
+    }
+
+    #[test]
+    fn parse_scientific_errors() {
+        for (s, expected) in [
+            ("1e NEAR", ParseError::InvalidUnit),
+            ("e5 NEAR", ParseError::InvalidUnit),
+            ("1e+ NEAR", ParseError::InvalidUnit),
+            ("1e1.5 NEAR", ParseError::InvalidNumber),
+            ("1e-+5 NEAR", ParseError::InvalidUnit),
+            ("1e5e5 NEAR", ParseError::InvalidNumber),
+            ("-1e5 NEAR", ParseError::InvalidNumber),
+            ("1.e5 NEAR", ParseError::InvalidNumber),
+            (".5e5 NEAR", ParseError::InvalidNumber),
+            ("5e-1 yoctoNEAR", ParseError::LongFractional),
+            ("1.5e-24 NEAR", ParseError::LongFractional),
+            ("1e-99999999999999999999 NEAR", ParseError::LongFractional),
+            ("1e15 NEAR", ParseError::ExponentOverflow),
+            ("1e39 yoctoNEAR", ParseError::ExponentOverflow),
+            (
+                "3.40282366920938463463374607431768211456e38 yN",
+                ParseError::ExponentOverflow,
+            ),
+            ("1e99999999999999999999 NEAR", ParseError::ExponentOverflow),
+        ] {
+            assert_eq!(NearToken::parse_const(s), Err(expected), "input: {:?}", s);
+        }
+    }
+
+    #[test]
+    fn parse_with_group_separators() {
+        let options = ParseOptions::new().any_group_separator();
+        for (s, expected) in [
+            ("1_000 NEAR", NearToken::from_near(1_000)),
+            ("1,000.5 NEAR", NearToken::from_millinear(1_000_500)),
+            ("1 000 NEAR", NearToken::from_near(1_000)),
+            ("1\u{a0}000 NEAR", NearToken::from_near(1_000)),
+            (
+                "1\u{2009}000\u{2009}000 NEAR",
+                NearToken::from_near(1_000_000),
+            ),
+            ("1\u{202f}000 NEAR", NearToken::from_near(1_000)),
+            ("1'000 NEAR", NearToken::from_near(1_000)),
+            ("1\u{2019}000 NEAR", NearToken::from_near(1_000)),
+            ("+12,345,678 yN", NearToken::from_yoctonear(12_345_678)),
+            ("0.000_001 NEAR", NearToken::from_micronear(1)),
+            ("0.123_45 NEAR", NearToken::from_micronear(123_450)),
+            (
+                "123,456.789,1 milliNEAR",
+                NearToken::from_yoctonear(123_456_789_100 * 10u128.pow(15)),
+            ),
+            ("1_000e3 yN", NearToken::from_yoctonear(1_000_000)),
+            ("1000.000_1 NEAR", NearToken::from_micronear(1_000_000_100)),
+            ("1000 NEAR", NearToken::from_near(1_000)),
+        ] {
+            assert_eq!(
+                NearToken::parse_with(s, &options),
+                Ok(expected),
+                "input: {:?}",
+                s
+            );
+        }
+
+        for (s, expected) in [
+            ("1,00 NEAR", ParseError::InvalidDigitGrouping),
+            ("1,0000 NEAR", ParseError::InvalidDigitGrouping),
+            ("1000,000 NEAR", ParseError::InvalidDigitGrouping),
+            ("1__000 NEAR", ParseError::InvalidDigitGrouping),
+            ("_1_000 NEAR", ParseError::InvalidDigitGrouping),
+            ("1_000_ NEAR", ParseError::InvalidDigitGrouping),
+            ("1_000._5 NEAR", ParseError::InvalidDigitGrouping),
+            ("0.12_3 NEAR", ParseError::InvalidDigitGrouping),
+            ("0.1234_5 NEAR", ParseError::InvalidDigitGrouping),
+            ("1  000 NEAR", ParseError::InvalidDigitGrouping),
+            ("1,000_000 NEAR", ParseError::InvalidNumber),
+            ("1_000e1_0 NEAR", ParseError::InvalidNumber),
+            ("1,000.5 yN", ParseError::LongFractional),
+        ] {
+            assert_eq!(
+                NearToken::parse_with(s, &options),
+                Err(expected),
+                "input: {:?}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn parse_with_selected_group_separators() {
+        let options = ParseOptions::new().group_separator(GroupSeparator::Underscore);
+        assert_eq!(
+            NearToken::parse_with("1_000 NEAR", &options),
+            Ok(NearToken::from_near(1_000))
+        );
+        assert_eq!(
+            NearToken::parse_with("1,000 NEAR", &options),
+            Err(ParseError::InvalidNumber)
+        );
+        for s in ["1_000 NEAR", "1,000 NEAR", "1 000 NEAR", "1'000 NEAR"] {
+            assert_eq!(
+                NearToken::parse_with(s, &ParseOptions::new()),
+                Err(ParseError::InvalidNumber)
+            );
+            assert_eq!(NearToken::parse_const(s), Err(ParseError::InvalidNumber));
+        }
+        assert_eq!(ParseOptions::default(), ParseOptions::new());
+    }
+
+    #[test]
+    fn parse_const_errors() {
+        for (s, expected) in [
+            ("", ParseError::MissingUnit),
+            ("1.25", ParseError::MissingUnit),
+            ("1 UAH", ParseError::InvalidUnit),
+            ("1 NE AR", ParseError::InvalidUnit),
+            ("1.1.1 NEAR", ParseError::InvalidNumber),
+            ("1. 0 NEAR", ParseError::InvalidNumber),
+            (".5 NEAR", ParseError::InvalidNumber),
+            ("5. NEAR", ParseError::InvalidNumber),
+            ("-1 NEAR", ParseError::InvalidNumber),
+            ("+ NEAR", ParseError::InvalidNumber),
+            ("1.+5 NEAR", ParseError::InvalidNumber),
+            ("1.5 yoctoNEAR", ParseError::LongFractional),
+            (
+                "0.0000000000000000000000001 NEAR",
+                ParseError::LongFractional,
+            ),
+            (
+                "0.00000000000000000000000000000000000000001 NEAR",
+                ParseError::LongFractional,
+            ),
+            (
+                "340282366920938.463463374607431768211456 NEAR",
+                ParseError::LongWhole,
+            ),
+            ("340282366920939 NEAR", ParseError::LongWhole),
+            (
+                "340282366920938463463374607431768211456 yoctoNEAR",
+                ParseError::LongWhole,
+            ),
+        ] {
+            assert_eq!(NearToken::parse_const(s), Err(expected), "input: {:?}", s);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{NearToken, Rounding};
+
+/// A non-negative fraction `numerator / denominator` that can be applied to a `NearToken` amount,
+/// e.g. a protocol fee, a staking commission or a slippage limit.
+///
+/// Ratios above one are allowed, the result of applying them is checked for overflow.
+///
+/// # Examples
+/// ```
+/// use near_token::{NearToken, Ratio};
+///
+/// let fee = Ratio::new(3, 1000);
+/// assert_eq!(NearToken::from_near(10).apply_ratio(fee), Some(NearToken::from_millinear(30)));
+/// assert_eq!(Ratio::from_percent(5), Ratio::from_basis_points(500));
+/// ```
+#[derive(Debug, Clone, Copy, Eq)]
+pub struct Ratio {
+    numerator: u128,
+    denominator: u128,
+}
+
+impl Ratio {
+    /// Ratio equal to zero
+    pub const ZERO: Ratio = Ratio::new(0, 1);
+    /// Ratio equal to one
+    pub const ONE: Ratio = Ratio::new(1, 1);
+
+    /// `new` creates a `numerator / denominator` ratio.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::Ratio;
+    /// assert_eq!(Ratio::new(1, 4).numerator(), 1);
+    /// assert_eq!(Ratio::new(1, 4).denominator(), 4);
+    /// ```
+    pub const fn new(numerator: u128, denominator: u128) -> Self {
+        assert!(denominator != 0, "Ratio denominator must not be zero");
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// `checked_new` creates a `numerator / denominator` ratio, returning None if `denominator` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::Ratio;
+    /// assert_eq!(Ratio::checked_new(1, 4), Some(Ratio::new(1, 4)));
+    /// assert_eq!(Ratio::checked_new(1, 0), None);
+    /// ```
+    pub const fn checked_new(numerator: u128, denominator: u128) -> Option<Self> {
+        if denominator == 0 {
+            None
+        } else {
+            Some(Self::new(numerator, denominator))
+        }
+    }
+
+    /// `from_percent` creates a ratio of `percent / 100`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::Ratio;
+    /// assert_eq!(Ratio::from_percent(25), Ratio::new(1, 4));
+    /// ```
+    pub const fn from_percent(percent: u128) -> Self {
+        Self::new(percent, 100)
+    }
+
+    /// `from_basis_points` creates a ratio of `basis_points / 10_000`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::Ratio;
+    /// assert_eq!(Ratio::from_basis_points(30), Ratio::new(3, 1000));
+    /// ```
+    pub const fn from_basis_points(basis_points: u16) -> Self {
+        Self::new(basis_points as u128, 10_000)
+    }
+
+    /// `numerator` returns the numerator of the ratio as it was created (the ratio is not reduced).
+    pub const fn numerator(&self) -> u128 {
+        self.numerator
+    }
+
+    /// `denominator` returns the denominator of the ratio as it was created (the ratio is not reduced).
+    pub const fn denominator(&self) -> u128 {
+        self.denominator
+    }
+}
+
+/// Ratios are compared by value, so `1/2 == 2/4`.
+impl PartialEq for Ratio {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        // a/b <=> c/d is the same as a*d <=> c*b for positive denominators.
+        crate::utils::widening_mul(self.numerator, other.denominator).cmp(
+            &crate::utils::widening_mul(other.numerator, self.denominator),
+        )
+    }
+}
+
+impl NearToken {
+    /// Checked ratio application. Computes self * ratio rounding down,
+    /// returning None if the result does not fit into `NearToken`.
+    ///
+    /// The intermediate product is computed with 256-bit precision, so it never overflows on its own.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, Ratio};
+    /// assert_eq!(NearToken::from_yoctonear(1000).apply_ratio(Ratio::new(3, 1000)), Some(NearToken::from_yoctonear(3)));
+    /// assert_eq!(NearToken::from_yoctonear(999).apply_ratio(Ratio::new(3, 1000)), Some(NearToken::from_yoctonear(2)));
+    /// assert_eq!(NearToken::MAX.apply_ratio(Ratio::new(2, 1)), None);
+    /// ```
+    pub const fn apply_ratio(self, ratio: Ratio) -> Option<Self> {
+        self.apply_ratio_rounded(ratio, Rounding::Down)
+    }
+
+    /// Checked ratio application. Computes self * ratio with the given rounding,
+    /// returning None if the result does not fit into `NearToken`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, Ratio, Rounding};
+    /// assert_eq!(NearToken::from_yoctonear(999).apply_ratio_rounded(Ratio::new(3, 1000), Rounding::Up), Some(NearToken::from_yoctonear(3)));
+    /// assert_eq!(NearToken::from_yoctonear(999).apply_ratio_rounded(Ratio::new(3, 1000), Rounding::Down), Some(NearToken::from_yoctonear(2)));
+    /// ```
+    pub const fn apply_ratio_rounded(self, ratio: Ratio, rounding: Rounding) -> Option<Self> {
+        self.checked_mul_div(ratio.numerator, ratio.denominator, rounding)
+    }
+
+    /// Checked percentage. Computes self * percent / 100 rounding down,
+    /// returning None if the result does not fit into `NearToken`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_near(3).checked_percent(50), Some(NearToken::from_millinear(1500)));
+    /// assert_eq!(NearToken::MAX.checked_percent(100), Some(NearToken::MAX));
+    /// assert_eq!(NearToken::MAX.checked_percent(101), None);
+    /// ```
+    pub const fn checked_percent(self, percent: u128) -> Option<Self> {
+        self.apply_ratio(Ratio::from_percent(percent))
+    }
+
+    /// Checked basis points. Computes self * basis_points / 10_000 rounding down,
+    /// returning None if the result does not fit into `NearToken`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// assert_eq!(NearToken::from_near(10).checked_basis_points(30), Some(NearToken::from_millinear(30)));
+    /// assert_eq!(NearToken::MAX.checked_basis_points(10_001), None);
+    /// ```
+    pub const fn checked_basis_points(self, basis_points: u16) -> Option<Self> {
+        self.apply_ratio(Ratio::from_basis_points(basis_points))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NearToken, Ratio, Rounding};
+
+    #[test]
+    fn ratio_eq_and_ord() {
+        assert_eq!(Ratio::new(1, 2), Ratio::new(2, 4));
+        assert_eq!(Ratio::new(0, 2), Ratio::ZERO);
+        assert!(Ratio::new(1, 3) < Ratio::new(1, 2));
+        assert!(Ratio::new(u128::MAX, u128::MAX - 1) > Ratio::ONE);
+        assert!(Ratio::new(u128::MAX - 1, u128::MAX) < Ratio::ONE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ratio denominator must not be zero")]
+    fn ratio_zero_denominator() {
+        let _ = Ratio::new(1, 0);
+    }
+
+    #[test]
+    fn apply_ratio() {
+        let tokens = NearToken::from_yoctonear(u128::MAX);
+        assert_eq!(tokens.apply_ratio(Ratio::ONE), Some(tokens));
+        assert_eq!(tokens.apply_ratio(Ratio::ZERO), Some(NearToken::ZERO));
+        assert_eq!(
+            tokens.apply_ratio(Ratio::new(u128::MAX - 1, u128::MAX)),
+            Some(NearToken::from_yoctonear(u128::MAX - 1))
+        );
+        assert_eq!(
+            NearToken::from_yoctonear(5).apply_ratio_rounded(Ratio::new(1, 2), Rounding::HalfEven),
+            Some(NearToken::from_yoctonear(2))
+        );
+        assert_eq!(
+            NearToken::from_yoctonear(5).apply_ratio_rounded(Ratio::new(1, 2), Rounding::HalfUp),
+            Some(NearToken::from_yoctonear(3))
+        );
+    }
+
+    #[test]
+    fn percent_and_basis_points() {
+        let tokens = NearToken::from_yoctonear(12_345);
+        assert_eq!(
+            tokens.checked_percent(10),
+            Some(NearToken::from_yoctonear(1_234))
+        );
+        assert_eq!(
+            tokens.checked_basis_points(1),
+            Some(NearToken::from_yoctonear(1))
+        );
+        assert_eq!(
+            tokens.checked_basis_points(u16::MAX),
+            Some(NearToken::from_yoctonear(80_902))
+        );
+        assert_eq!(tokens.checked_percent(0), Some(NearToken::ZERO));
+        assert_eq!(NearToken::MAX.checked_percent(200), None);
+    }
+}
@@ -0,0 +1,128 @@
+/// Rounding mode used whenever an operation on `NearToken` cannot be represented exactly in yoctoNEAR
+/// or in the requested precision.
+///
+/// For unsigned amounts `Down` and `TowardZero` are equivalent, they only differ for signed values
+/// such as `NearTokenDelta`.
+///
+/// # Examples
+/// ```
+/// use near_token::{NearToken, Rounding};
+///
+/// let tokens = NearToken::from_yoctonear(10);
+/// assert_eq!(tokens.checked_mul_div(1, 4, Rounding::Down), Some(NearToken::from_yoctonear(2)));
+/// assert_eq!(tokens.checked_mul_div(1, 4, Rounding::Up), Some(NearToken::from_yoctonear(3)));
+/// assert_eq!(tokens.checked_mul_div(1, 4, Rounding::HalfUp), Some(NearToken::from_yoctonear(3)));
+/// assert_eq!(tokens.checked_mul_div(1, 4, Rounding::HalfEven), Some(NearToken::from_yoctonear(2)));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round toward negative infinity (floor).
+    #[default]
+    Down,
+    /// Round toward positive infinity (ceiling).
+    Up,
+    /// Round to the nearest value, ties are rounded to the even neighbour (banker's rounding).
+    HalfEven,
+    /// Round to the nearest value, ties are rounded away from zero.
+    HalfUp,
+    /// Round toward zero (truncation).
+    TowardZero,
+}
+
+impl Rounding {
+    /// Rounds the non-negative `quotient + remainder / denominator` to an integer,
+    /// returning None if the rounded value does not fit into `u128`.
+    ///
+    /// `remainder` must be less than `denominator`.
+    pub(crate) const fn round_quotient(
+        self,
+        quotient: u128,
+        remainder: u128,
+        denominator: u128,
+    ) -> Option<u128> {
+        let round_up = if remainder == 0 {
+            false
+        } else {
+            // `remainder < denominator`, so `denominator - remainder` never underflows and the
+            // comparison below is equivalent to comparing `2 * remainder` with `denominator`.
+            let rest = denominator - remainder;
+            match self {
+                Rounding::Down | Rounding::TowardZero => false,
+                Rounding::Up => true,
+                Rounding::HalfUp => remainder >= rest,
+                Rounding::HalfEven => remainder > rest || (remainder == rest && quotient % 2 == 1),
+            }
+        };
+        if round_up {
+            quotient.checked_add(1)
+        } else {
+            Some(quotient)
+        }
+    }
+
+    /// Divides `numerator` by `denominator` rounding the result with this mode,
+    /// returning None if `denominator == 0`.
+    pub(crate) const fn divide(self, numerator: u128, denominator: u128) -> Option<u128> {
+        if denominator == 0 {
+            return None;
+        }
+        self.round_quotient(
+            numerator / denominator,
+            numerator % denominator,
+            denominator,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Rounding;
+
+    #[test]
+    fn divide() {
+        for (numerator, denominator, down, up, half_even, half_up) in [
+            (0, 4, 0, 0, 0, 0),
+            (8, 4, 2, 2, 2, 2),
+            (9, 4, 2, 3, 2, 2),
+            (10, 4, 2, 3, 2, 3),
+            (11, 4, 2, 3, 3, 3),
+            (14, 4, 3, 4, 4, 4),
+            (5, 3, 1, 2, 2, 2),
+            (u128::MAX, 1, u128::MAX, u128::MAX, u128::MAX, u128::MAX),
+            (
+                u128::MAX,
+                2,
+                u128::MAX / 2,
+                u128::MAX / 2 + 1,
+                u128::MAX / 2 + 1,
+                u128::MAX / 2 + 1,
+            ),
+        ] {
+            assert_eq!(Rounding::Down.divide(numerator, denominator), Some(down));
+            assert_eq!(
+                Rounding::TowardZero.divide(numerator, denominator),
+                Some(down)
+            );
+            assert_eq!(Rounding::Up.divide(numerator, denominator), Some(up));
+            assert_eq!(
+                Rounding::HalfEven.divide(numerator, denominator),
+                Some(half_even)
+            );
+            assert_eq!(
+                Rounding::HalfUp.divide(numerator, denominator),
+                Some(half_up)
+            );
+        }
+        assert_eq!(Rounding::Down.divide(1, 0), None);
+    }
+
+    #[test]
+    fn round_quotient_overflow() {
+        assert_eq!(Rounding::Up.round_quotient(u128::MAX, 1, 2), None);
+        assert_eq!(Rounding::HalfUp.round_quotient(u128::MAX, 1, 2), None);
+        assert_eq!(
+            Rounding::Down.round_quotient(u128::MAX, 1, 2),
+            Some(u128::MAX)
+        );
+    }
+}
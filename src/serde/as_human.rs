@@ -0,0 +1,30 @@
+//! Serializes `NearToken` as a human-readable string with the exact amount and its unit, e.g. `"1.5 NEAR"`.
+//!
+//! Amounts are serialized like [`NearToken::exact`], and deserialization accepts the same syntax as
+//! `NearToken::from_str`, e.g. `"1500 milliNEAR"`.
+
+use ::serde::{Deserializer, Serializer};
+
+use super::StrVisitor;
+use crate::NearToken;
+
+/// Serializes `tokens` as a string with the exact amount and its unit.
+pub fn serialize<S: Serializer>(tokens: &NearToken, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&tokens.exact())
+}
+
+/// Deserializes `NearToken` from a string with an amount and its unit.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NearToken, D::Error> {
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "a string with an amount of NEAR and its unit",
+        parse: NearToken::parse_const,
+    })
+}
+
+json_schema! {
+    type: string,
+    description: "Amount of NEAR tokens with its unit, from yoctoNEAR (10^-24 NEAR) to megaNEAR (10^6 NEAR)",
+    examples: ["1.5 NEAR", "500 yoctoNEAR"],
+}
+
+option_and_vec!();
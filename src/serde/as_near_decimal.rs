@@ -0,0 +1,38 @@
+//! Serializes `NearToken` as a string with the exact decimal amount of NEAR without the unit, e.g. `"1.5"`.
+//!
+//! Deserialization accepts plain decimal numbers with at most 24 fractional digits.
+
+use ::serde::{Deserializer, Serializer};
+
+use super::StrVisitor;
+use crate::{NearToken, NearTokenFormatter, NearUnit, ParseOptions};
+
+/// Serializes `tokens` as a string with the exact decimal amount of NEAR.
+pub fn serialize<S: Serializer>(tokens: &NearToken, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&NearTokenFormatter::new().show_unit(false).format(*tokens))
+}
+
+/// Deserializes `NearToken` from a string with the decimal amount of NEAR.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NearToken, D::Error> {
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "a string with a decimal amount of NEAR",
+        parse: |s| {
+            crate::parse::parse_decimal(
+                s.as_bytes(),
+                0,
+                s.len(),
+                NearUnit::Near.multiplier(),
+                &ParseOptions::new(),
+            )
+            .map(|(yoctonear, _)| NearToken::from_yoctonear(yoctonear))
+        },
+    })
+}
+
+json_schema! {
+    type: string,
+    description: "Amount of NEAR tokens as a decimal number of NEAR with at most 24 fractional digits",
+    examples: ["1.5", "0.000000000000000000000001"],
+}
+
+option_and_vec!();
@@ -0,0 +1,38 @@
+//! Serializes `NearToken` as a hexadecimal string with the amount of yoctoNEAR, e.g. `"0x13da329b6336471800000"`.
+//!
+//! The `0x` prefix is required when deserializing, and the digits may be in any letter case.
+
+use ::serde::{Deserializer, Serializer};
+
+use super::StrVisitor;
+use crate::NearToken;
+
+/// Serializes `tokens` as a hexadecimal string with the amount of yoctoNEAR.
+pub fn serialize<S: Serializer>(tokens: &NearToken, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{:#x}", tokens.as_yoctonear()))
+}
+
+/// Deserializes `NearToken` from a hexadecimal string with the amount of yoctoNEAR.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NearToken, D::Error> {
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "a hexadecimal string with an amount of yoctoNEAR",
+        parse: |s| {
+            let digits = s
+                .strip_prefix("0x")
+                .or_else(|| s.strip_prefix("0X"))
+                .ok_or("missing 0x prefix")?;
+            u128::from_str_radix(digits, 16)
+                .map(NearToken::from_yoctonear)
+                .map_err(|_| "invalid hexadecimal amount")
+        },
+    })
+}
+
+json_schema! {
+    type: string,
+    pattern: "^0[xX][0-9a-fA-F]{1,32}$",
+    description: "Amount of NEAR tokens in yoctoNEAR (10^-24 NEAR) as a hexadecimal string with the 0x prefix",
+    examples: ["0xd3c21bcecceda1000000", "0x0"],
+}
+
+option_and_vec!();
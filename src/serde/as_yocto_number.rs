@@ -0,0 +1,52 @@
+//! Serializes `NearToken` as a number with the amount of yoctoNEAR, e.g. `1500000000000000000000000`.
+//!
+//! The amount may not fit into the 64-bit integers or the floating point numbers of other JSON implementations,
+//! so use it only with the APIs that expect it.
+
+use ::serde::{de, Deserializer, Serializer};
+
+use crate::NearToken;
+
+/// Serializes `tokens` as a number with the amount of yoctoNEAR.
+pub fn serialize<S: Serializer>(tokens: &NearToken, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(tokens.as_yoctonear())
+}
+
+/// Deserializes `NearToken` from a non-negative integer with the amount of yoctoNEAR.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NearToken, D::Error> {
+    deserializer.deserialize_u128(NumberVisitor)
+}
+
+struct NumberVisitor;
+
+impl<'de> de::Visitor<'de> for NumberVisitor {
+    type Value = NearToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a non-negative integer with an amount of yoctoNEAR")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(NearToken::from_yoctonear(value.into()))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        Ok(NearToken::from_yoctonear(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u128::try_from(value)
+            .map(NearToken::from_yoctonear)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+}
+
+json_schema! {
+    type: integer,
+    format: "uint128",
+    minimum: 0,
+    description: "Amount of NEAR tokens in yoctoNEAR (10^-24 NEAR) as a number",
+    examples: [1000],
+}
+
+option_and_vec!();
@@ -0,0 +1,32 @@
+//! Serializes `NearToken` as a string with the amount of yoctoNEAR, e.g. `"1500000000000000000000000"`.
+//!
+//! It is the representation of `NearToken` itself, spelled out for fields that must keep it
+//! regardless of the serializer.
+//!
+//! Up to 0.3.3 `NearToken` was serialized as this string in binary formats such as bincode too,
+//! so fields annotated with this module keep reading and writing the data stored by those versions.
+
+use ::serde::{Deserializer, Serializer};
+
+use super::StrVisitor;
+use crate::NearToken;
+
+/// Serializes `tokens` as a string with the amount of yoctoNEAR.
+pub fn serialize<S: Serializer>(tokens: &NearToken, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&tokens.as_yoctonear())
+}
+
+/// Deserializes `NearToken` from a string with the amount of yoctoNEAR.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NearToken, D::Error> {
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "a string with an amount of yoctoNEAR",
+        parse: |s| s.parse().map(NearToken::from_yoctonear),
+    })
+}
+
+json_schema! {
+    v0_8: |_generator| crate::trait_impls::schemars::near_token_schema_v0_8(),
+    v1: |_generator| crate::trait_impls::schemars::near_token_schema_v1(),
+}
+
+option_and_vec!();
@@ -0,0 +1,526 @@
+//! Alternative serde representations of `NearToken` to be used with `#[serde(with = "...")]`.
+//!
+//! `NearToken` itself is serialized as a string with the amount of yoctoNEAR. The modules below select
+//! another representation for a field, and each of them has `option` and `vec` submodules
+//! for `Option<NearToken>` and `Vec<NearToken>` fields (`vec` requires the **alloc** feature).
+//!
+//! | Module              | Representation of 1.5 NEAR        |
+//! |---------------------|-----------------------------------|
+//! | [`as_yocto_str`]    | `"1500000000000000000000000"`     |
+//! | [`as_yocto_number`] | `1500000000000000000000000`       |
+//! | [`as_yocto_hex`]    | `"0x13da329b6336471800000"`       |
+//! | [`as_near_decimal`] | `"1.5"`                           |
+//! | [`as_human`]        | `"1.5 NEAR"`                      |
+//!
+//! [`permissive`] serializes amounts as [`as_yocto_str`] does, but deserializes any of the representations
+//! above except the hexadecimal and decimal ones, e.g. `1000`, `"1000"` or `"1.5 NEAR"`.
+//!
+//! A field is annotated with the module, e.g. `#[serde(with = "near_token::serde::as_human")]`
+//! for `NearToken` or `#[serde(with = "near_token::serde::as_human::option")]` for `Option<NearToken>`.
+//! With the **schemars-v0_8** or **schemars-v1** features, every module also describes its representation
+//! with `json_schema_v0_8` or `json_schema_v1` for `#[schemars(schema_with = "...")]`,
+//! e.g. `#[schemars(schema_with = "near_token::serde::as_human::json_schema_v1")]`.
+//! The functions of the modules can also be called directly:
+//!
+//! # Examples
+//! ```
+//! use near_token::NearToken;
+//!
+//! let tokens = NearToken::from_millinear(1_500);
+//! let json = near_token::serde::as_human::serialize(&tokens, serde_json::value::Serializer).unwrap();
+//! assert_eq!(json, serde_json::json!("1.5 NEAR"));
+//! assert_eq!(near_token::serde::as_near_decimal::deserialize(serde_json::json!("1.5")).unwrap(), tokens);
+//! ```
+
+use core::fmt::Display;
+
+use ::serde::de;
+
+use crate::NearToken;
+
+/// Generates the JSON schema functions of a representation module, to be used with `#[schemars(schema_with = "...")]`,
+/// either from the keywords of a string or integer schema, or from the bodies of the functions of both schemars versions.
+macro_rules! json_schema {
+    (
+        type: $type:tt,
+        $(format: $format:literal,)?
+        $(pattern: $pattern:literal,)?
+        $(minimum: $minimum:literal,)?
+        description: $description:expr,
+        examples: [$($example:expr),* $(,)?] $(,)?
+    ) => {
+        json_schema! {
+            v0_8: |_generator| {
+                use schemars_v0_8::schema::{Metadata, SchemaObject};
+                SchemaObject {
+                    instance_type: Some(json_schema!(@instance_type_v0_8 $type)),
+                    format: json_schema!(@option $($format.to_owned())?),
+                    metadata: Some(Box::new(Metadata {
+                        description: Some($description.to_owned()),
+                        examples: vec![$($example.into()),*],
+                        ..Default::default()
+                    })),
+                    string: json_schema!(@option $(Box::new(schemars_v0_8::schema::StringValidation {
+                        pattern: Some($pattern.to_owned()),
+                        ..Default::default()
+                    }))?),
+                    number: json_schema!(@option $(Box::new(schemars_v0_8::schema::NumberValidation {
+                        minimum: Some($minimum as f64),
+                        ..Default::default()
+                    }))?),
+                    ..Default::default()
+                }
+                .into()
+            },
+            v1: |_generator| schemars_v1::json_schema!({
+                "type": json_schema!(@type_v1 $type),
+                $("format": $format,)?
+                $("pattern": $pattern,)?
+                $("minimum": $minimum,)?
+                "description": $description,
+                "examples": [$($example),*],
+            }),
+        }
+    };
+    (
+        v0_8: |$generator_v0_8:ident| $v0_8:expr,
+        v1: |$generator_v1:ident| $v1:expr $(,)?
+    ) => {
+        /// Generates the JSON schema of this representation for schemars 0.8.
+        #[cfg(feature = "schemars-v0_8")]
+        pub fn json_schema_v0_8(
+            $generator_v0_8: &mut schemars_v0_8::gen::SchemaGenerator,
+        ) -> schemars_v0_8::schema::Schema {
+            $v0_8
+        }
+
+        /// Generates the JSON schema of this representation for schemars 1.
+        #[cfg(feature = "schemars-v1")]
+        pub fn json_schema_v1($generator_v1: &mut schemars_v1::SchemaGenerator) -> schemars_v1::Schema {
+            $v1
+        }
+    };
+    (@option) => {
+        None
+    };
+    (@option $value:expr) => {
+        Some($value)
+    };
+    (@instance_type_v0_8 [$($type:ident),+]) => {
+        vec![$(json_schema!(@instance_type_v0_8 $type)),+].into()
+    };
+    (@instance_type_v0_8 integer) => {
+        schemars_v0_8::schema::InstanceType::Integer.into()
+    };
+    (@instance_type_v0_8 string) => {
+        schemars_v0_8::schema::InstanceType::String.into()
+    };
+    (@type_v1 [$($type:ident),+]) => {
+        [$(stringify!($type)),+]
+    };
+    (@type_v1 $type:ident) => {
+        stringify!($type)
+    };
+}
+
+/// Generates the `option` and `vec` submodules of a representation module
+/// from its `serialize` and `deserialize` functions.
+macro_rules! option_and_vec {
+    () => {
+        /// The same representation for `Option<NearToken>`, where `None` is serialized as none (`null` in JSON).
+        pub mod option {
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::NearToken;
+
+            json_schema! {
+                v0_8: |generator| {
+                    use schemars_v0_8::schema::{InstanceType, SchemaObject, SubschemaValidation};
+                    let null = SchemaObject {
+                        instance_type: Some(InstanceType::Null.into()),
+                        ..Default::default()
+                    };
+                    SchemaObject {
+                        subschemas: Some(Box::new(SubschemaValidation {
+                            any_of: Some(vec![super::json_schema_v0_8(generator), null.into()]),
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    }
+                    .into()
+                },
+                v1: |generator| schemars_v1::json_schema!({
+                    "anyOf": [super::json_schema_v1(generator), { "type": "null" }],
+                }),
+            }
+
+            struct Borrowed<'a>(&'a NearToken);
+
+            impl Serialize for Borrowed<'_> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(self.0, serializer)
+                }
+            }
+
+            struct Owned(NearToken);
+
+            impl<'de> Deserialize<'de> for Owned {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(Owned)
+                }
+            }
+
+            /// Serializes `Some` amount with the representation of the parent module.
+            pub fn serialize<S: Serializer>(
+                tokens: &Option<NearToken>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match tokens {
+                    Some(tokens) => serializer.serialize_some(&Borrowed(tokens)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            /// Deserializes an optional amount with the representation of the parent module.
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<NearToken>, D::Error> {
+                Ok(Option::<Owned>::deserialize(deserializer)?.map(|Owned(tokens)| tokens))
+            }
+        }
+
+        /// The same representation for `Vec<NearToken>`, serialized as a sequence.
+        #[cfg(feature = "alloc")]
+        pub mod vec {
+            use alloc::vec::Vec;
+
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use crate::NearToken;
+
+            json_schema! {
+                v0_8: |generator| {
+                    use schemars_v0_8::schema::{ArrayValidation, InstanceType, SchemaObject};
+                    SchemaObject {
+                        instance_type: Some(InstanceType::Array.into()),
+                        array: Some(Box::new(ArrayValidation {
+                            items: Some(super::json_schema_v0_8(generator).into()),
+                            ..Default::default()
+                        })),
+                        ..Default::default()
+                    }
+                    .into()
+                },
+                v1: |generator| schemars_v1::json_schema!({
+                    "type": "array",
+                    "items": super::json_schema_v1(generator),
+                }),
+            }
+
+            struct Borrowed<'a>(&'a NearToken);
+
+            impl Serialize for Borrowed<'_> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(self.0, serializer)
+                }
+            }
+
+            struct Owned(NearToken);
+
+            impl<'de> Deserialize<'de> for Owned {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(Owned)
+                }
+            }
+
+            /// Serializes the amounts as a sequence with the representation of the parent module.
+            pub fn serialize<S: Serializer>(
+                tokens: &[NearToken],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(tokens.iter().map(Borrowed))
+            }
+
+            /// Deserializes a sequence of amounts with the representation of the parent module.
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Vec<NearToken>, D::Error> {
+                Ok(Vec::<Owned>::deserialize(deserializer)?
+                    .into_iter()
+                    .map(|Owned(tokens)| tokens)
+                    .collect())
+            }
+        }
+    };
+}
+
+pub mod as_human;
+pub mod as_near_decimal;
+pub mod as_yocto_hex;
+pub mod as_yocto_number;
+pub mod as_yocto_str;
+pub mod permissive;
+
+/// Visitor of string representations, borrowing the string from the deserializer
+/// so no allocation is needed to parse it.
+struct StrVisitor<E> {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<NearToken, E>,
+}
+
+impl<'de, E: Display> de::Visitor<'de> for StrVisitor<E> {
+    type Value = NearToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<Err: de::Error>(self, s: &str) -> Result<Self::Value, Err> {
+        (self.parse)(s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{as_human, as_near_decimal, as_yocto_hex, as_yocto_number, as_yocto_str};
+    use crate::NearToken;
+
+    fn to_json(
+        serialize: impl FnOnce(&mut serde_json::Serializer<&mut Vec<u8>>) -> serde_json::Result<()>,
+    ) -> String {
+        let mut out = Vec::new();
+        serialize(&mut serde_json::Serializer::new(&mut out)).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    fn from_json(json: &str) -> serde_json::Deserializer<serde_json::de::StrRead<'_>> {
+        serde_json::Deserializer::from_str(json)
+    }
+
+    /// Checks that `tokens` are serialized by `$module` as `$expected` JSON, and its `option` and `vec`
+    /// submodules follow it, and that all of them deserialize the result back.
+    /// The `vec` submodule is only checked with the **alloc** feature.
+    macro_rules! round_trip {
+        ($module:ident, $tokens:expr, $expected:expr) => {{
+            let tokens: NearToken = $tokens;
+            let expected: &str = $expected;
+
+            assert_eq!(to_json(|s| $module::serialize(&tokens, s)), expected);
+            assert_eq!(
+                $module::deserialize(&mut from_json(expected)).unwrap(),
+                tokens
+            );
+
+            assert_eq!(
+                to_json(|s| $module::option::serialize(&Some(tokens), s)),
+                expected
+            );
+            assert_eq!(to_json(|s| $module::option::serialize(&None, s)), "null");
+            assert_eq!(
+                $module::option::deserialize(&mut from_json(expected)).unwrap(),
+                Some(tokens)
+            );
+            assert_eq!(
+                $module::option::deserialize(&mut from_json("null")).unwrap(),
+                None
+            );
+
+            #[cfg(feature = "alloc")]
+            {
+                let list = format!("[{},{}]", expected, expected);
+                assert_eq!(
+                    to_json(|s| $module::vec::serialize(&[tokens, tokens], s)),
+                    list
+                );
+                assert_eq!(to_json(|s| $module::vec::serialize(&[], s)), "[]");
+                assert_eq!(
+                    $module::vec::deserialize(&mut from_json(&list)).unwrap(),
+                    vec![tokens, tokens]
+                );
+                assert_eq!(
+                    $module::vec::deserialize(&mut from_json("[]")).unwrap(),
+                    vec![]
+                );
+            }
+        }};
+    }
+
+    #[test]
+    fn json_as_yocto_str() {
+        round_trip!(
+            as_yocto_str,
+            NearToken::from_millinear(1_500),
+            r#""1500000000000000000000000""#
+        );
+        round_trip!(as_yocto_str, NearToken::ZERO, r#""0""#);
+        round_trip!(as_yocto_str, NearToken::MAX, &format!(r#""{}""#, u128::MAX));
+    }
+
+    #[test]
+    fn json_as_yocto_number() {
+        round_trip!(
+            as_yocto_number,
+            NearToken::from_millinear(1_500),
+            "1500000000000000000000000"
+        );
+        round_trip!(as_yocto_number, NearToken::from_yoctonear(7), "7");
+        round_trip!(as_yocto_number, NearToken::MAX, &u128::MAX.to_string());
+    }
+
+    #[test]
+    fn json_as_yocto_hex() {
+        round_trip!(
+            as_yocto_hex,
+            NearToken::from_millinear(1_500),
+            r#""0x13da329b6336471800000""#
+        );
+        round_trip!(as_yocto_hex, NearToken::ZERO, r#""0x0""#);
+        round_trip!(
+            as_yocto_hex,
+            NearToken::MAX,
+            r#""0xffffffffffffffffffffffffffffffff""#
+        );
+    }
+
+    #[test]
+    fn json_as_near_decimal() {
+        round_trip!(
+            as_near_decimal,
+            NearToken::from_millinear(1_500),
+            r#""1.5""#
+        );
+        round_trip!(as_near_decimal, NearToken::from_near(2), r#""2""#);
+        round_trip!(as_near_decimal, NearToken::ZERO, r#""0""#);
+        round_trip!(
+            as_near_decimal,
+            NearToken::from_yoctonear(1),
+            r#""0.000000000000000000000001""#
+        );
+        round_trip!(
+            as_near_decimal,
+            NearToken::MAX,
+            r#""340282366920938.463463374607431768211455""#
+        );
+    }
+
+    #[test]
+    fn json_as_human() {
+        round_trip!(as_human, NearToken::from_millinear(1_500), r#""1.5 NEAR""#);
+        round_trip!(as_human, NearToken::ZERO, r#""0 NEAR""#);
+        round_trip!(
+            as_human,
+            NearToken::from_yoctonear(500),
+            r#""500 yoctoNEAR""#
+        );
+        round_trip!(
+            as_human,
+            NearToken::MAX,
+            r#""340282366920938.463463374607431768211455 NEAR""#
+        );
+        assert_eq!(
+            as_human::deserialize(json!("1500 milliNEAR")).unwrap(),
+            NearToken::from_millinear(1_500)
+        );
+    }
+
+    #[test]
+    fn json_invalid() {
+        assert_eq!(
+            as_human::deserialize(json!("1.5")).unwrap_err().to_string(),
+            "missing token unit"
+        );
+        assert_eq!(
+            as_near_decimal::deserialize(json!("1.5 NEAR"))
+                .unwrap_err()
+                .to_string(),
+            "invalid number"
+        );
+        assert!(as_near_decimal::deserialize(json!("0.0000000000000000000000001")).is_err());
+        assert!(as_yocto_hex::deserialize(json!("ff")).is_err());
+        assert!(as_yocto_hex::deserialize(json!("0x")).is_err());
+        assert!(as_yocto_hex::deserialize(json!("0x100000000000000000000000000000000")).is_err());
+        assert_eq!(
+            as_yocto_hex::deserialize(json!("0XFF")).unwrap(),
+            NearToken::from_yoctonear(255)
+        );
+        assert!(as_yocto_number::deserialize(json!("1000")).is_err());
+        assert!(as_yocto_number::deserialize(json!(-1)).is_err());
+        assert!(as_yocto_number::deserialize(json!(1.5)).is_err());
+        assert!(as_yocto_number::deserialize(&mut from_json(
+            "340282366920938463463374607431768211456"
+        ))
+        .is_err());
+        assert!(as_yocto_str::deserialize(json!(1000)).is_err());
+        assert!(as_yocto_str::deserialize(json!("-1")).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "schemars-v0_8", feature = "schemars-v1"))]
+    fn json_schemas() {
+        use super::permissive;
+
+        fn v1(
+            schema: fn(&mut schemars_v1::SchemaGenerator) -> schemars_v1::Schema,
+        ) -> serde_json::Value {
+            serde_json::to_value(schema(&mut Default::default())).unwrap()
+        }
+
+        assert_eq!(
+            v1(as_yocto_str::json_schema_v1),
+            json!({
+                "type": "string",
+                "format": "uint128",
+                "pattern": "^[0-9]{1,39}$",
+                "description": "Amount of NEAR tokens in yoctoNEAR (10^-24 NEAR) as a string of decimal digits",
+                "examples": ["1000000000000000000000000", "0"],
+            })
+        );
+        assert_eq!(
+            v1(as_yocto_hex::option::json_schema_v1),
+            json!({ "anyOf": [v1(as_yocto_hex::json_schema_v1), { "type": "null" }] })
+        );
+        assert_eq!(
+            v1(as_human::vec::json_schema_v1),
+            json!({ "type": "array", "items": v1(as_human::json_schema_v1) })
+        );
+        assert_eq!(v1(as_yocto_number::json_schema_v1)["type"], "integer");
+        assert_eq!(
+            v1(permissive::json_schema_v1)["type"],
+            json!(["integer", "string"])
+        );
+
+        for (schema_v0_8, schema_v1) in [
+            (
+                as_yocto_str::json_schema_v0_8 as fn(&mut _) -> _,
+                as_yocto_str::json_schema_v1 as fn(&mut _) -> _,
+            ),
+            (
+                as_yocto_number::json_schema_v0_8,
+                as_yocto_number::json_schema_v1,
+            ),
+            (as_yocto_hex::json_schema_v0_8, as_yocto_hex::json_schema_v1),
+            (
+                as_near_decimal::json_schema_v0_8,
+                as_near_decimal::json_schema_v1,
+            ),
+            (as_human::json_schema_v0_8, as_human::json_schema_v1),
+            (permissive::json_schema_v0_8, permissive::json_schema_v1),
+            (
+                as_human::option::json_schema_v0_8,
+                as_human::option::json_schema_v1,
+            ),
+            (
+                as_human::vec::json_schema_v0_8,
+                as_human::vec::json_schema_v1,
+            ),
+        ] {
+            // schemars 0.8 keeps numbers as floating point ones, so compare its schemas
+            assert_eq!(
+                schema_v0_8(&mut Default::default()),
+                serde_json::from_value(v1(schema_v1)).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Deserializes `NearToken` from any of the forms people write amounts in, e.g. in configuration files.
+//!
+//! The accepted forms are:
+//! * a non-negative integer with the amount of yoctoNEAR, e.g. `1000`, including the numbers of
+//!   `serde_json` with the `arbitrary_precision` feature that do not fit into 64 bits;
+//! * a string with the amount of yoctoNEAR, e.g. `"1000"`;
+//! * a string with an amount and its unit accepted by `NearToken::from_str`, e.g. `"0.5 NEAR"`.
+//!
+//! Amounts are serialized as a string with the amount of yoctoNEAR, the representation of `NearToken` itself.
+//!
+//! The form of an amount is only known from the input itself, so deserialization relies on
+//! `Deserializer::deserialize_any` and works only with self-describing formats such as JSON, TOML or YAML.
+//! Formats that are not self-describing, such as bincode or postcard, fail to deserialize amounts with this module.
+//!
+//! # Examples
+//! ```
+//! use near_token::NearToken;
+//! use near_token::serde::permissive;
+//! use serde_json::json;
+//!
+//! assert_eq!(permissive::deserialize(json!(1000)).unwrap(), NearToken::from_yoctonear(1000));
+//! assert_eq!(permissive::deserialize(json!("1000")).unwrap(), NearToken::from_yoctonear(1000));
+//! assert_eq!(permissive::deserialize(json!("0.5 NEAR")).unwrap(), NearToken::from_millinear(500));
+//! assert_eq!(
+//!     permissive::deserialize(json!("0.5 NAER")).unwrap_err().to_string(),
+//!     "invalid amount \"0.5 NAER\": invalid token unit, expected an integer amount of yoctoNEAR, \
+//!      a string with an amount of yoctoNEAR, or a string with an amount and its unit such as \"1.5 NEAR\""
+//! );
+//! ```
+
+use ::serde::{de, Deserializer, Serializer};
+
+use crate::NearToken;
+
+/// Description of the accepted forms used in the errors.
+const EXPECTING: &str = "an integer amount of yoctoNEAR, a string with an amount of yoctoNEAR, \
+                         or a string with an amount and its unit such as \"1.5 NEAR\"";
+
+/// The only key of the map that represents a number of `serde_json` with the `arbitrary_precision` feature.
+const ARBITRARY_PRECISION_NUMBER: &str = "$serde_json::private::Number";
+
+/// Serializes `tokens` as a string with the amount of yoctoNEAR.
+pub fn serialize<S: Serializer>(tokens: &NearToken, serializer: S) -> Result<S::Ok, S::Error> {
+    super::as_yocto_str::serialize(tokens, serializer)
+}
+
+/// Deserializes `NearToken` from an integer or a string with the amount of yoctoNEAR,
+/// or a string with an amount and its unit.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NearToken, D::Error> {
+    deserializer.deserialize_any(PermissiveVisitor)
+}
+
+struct PermissiveVisitor;
+
+impl PermissiveVisitor {
+    fn parse<E: de::Error>(s: &str) -> Result<NearToken, E> {
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            return s.parse().map(NearToken::from_yoctonear).map_err(|_| {
+                E::custom(format_args!(
+                    "invalid amount {:?}: too large amount of yoctoNEAR, expected {}",
+                    s, EXPECTING
+                ))
+            });
+        }
+        NearToken::parse_const(s).map_err(|err| {
+            E::custom(format_args!(
+                "invalid amount {:?}: {}, expected {}",
+                s, err, EXPECTING
+            ))
+        })
+    }
+}
+
+impl<'de> de::Visitor<'de> for PermissiveVisitor {
+    type Value = NearToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(EXPECTING)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(NearToken::from_yoctonear(value.into()))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        Ok(NearToken::from_yoctonear(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u128::try_from(value)
+            .map(NearToken::from_yoctonear)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+        u128::try_from(value)
+            .map(NearToken::from_yoctonear)
+            .map_err(|_| E::invalid_value(de::Unexpected::Other("negative integer"), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        Self::parse(s)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        match map.next_key_seed(NumberKey)? {
+            Some(()) => map.next_value_seed(NumberValue),
+            None => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
+        }
+    }
+}
+
+/// Accepts only the key of an arbitrary precision number of `serde_json`.
+struct NumberKey;
+
+impl<'de> de::DeserializeSeed<'de> for NumberKey {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for NumberKey {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, key: &str) -> Result<(), E> {
+        if key == ARBITRARY_PRECISION_NUMBER {
+            Ok(())
+        } else {
+            Err(E::invalid_type(de::Unexpected::Map, &self))
+        }
+    }
+}
+
+/// Parses the digits of an arbitrary precision number of `serde_json`.
+struct NumberValue;
+
+impl<'de> de::DeserializeSeed<'de> for NumberValue {
+    type Value = NearToken;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<NearToken, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for NumberValue {
+    type Value = NearToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, number: &str) -> Result<NearToken, E> {
+        number.parse().map(NearToken::from_yoctonear).map_err(|_| {
+            E::custom(format_args!(
+                "invalid amount {}: not a non-negative integer amount of yoctoNEAR, expected {}",
+                number, EXPECTING
+            ))
+        })
+    }
+}
+
+json_schema! {
+    type: [integer, string],
+    minimum: 0,
+    description: "Amount of NEAR tokens as a number or a string of yoctoNEAR (10^-24 NEAR), \
+                  or a string with the amount and its unit",
+    examples: [1000, "1000", "0.5 NEAR"],
+}
+
+option_and_vec!();
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{deserialize, option};
+    use crate::NearToken;
+
+    #[test]
+    fn json_permissive() {
+        for (value, expected) in [
+            (json!(0), NearToken::ZERO),
+            (json!(1000), NearToken::from_yoctonear(1000)),
+            (json!(u64::MAX), NearToken::from_yoctonear(u64::MAX.into())),
+            (json!("1000"), NearToken::from_yoctonear(1000)),
+            (json!(u128::MAX.to_string()), NearToken::MAX),
+            (json!("0.5 NEAR"), NearToken::from_millinear(500)),
+            (json!(" 2 near "), NearToken::from_near(2)),
+            (json!("1e3 yoctoNEAR"), NearToken::from_yoctonear(1000)),
+        ] {
+            assert_eq!(deserialize(value.clone()).unwrap(), expected, "{}", value);
+        }
+        assert_eq!(
+            serde_json::from_str::<NearToken>(&format!("\"{}\"", u128::MAX)).unwrap(),
+            NearToken::MAX
+        );
+        assert_eq!(
+            deserialize(&mut serde_json::Deserializer::from_str("123")).unwrap(),
+            NearToken::from_yoctonear(123)
+        );
+        assert_eq!(
+            option::deserialize(json!("1 NEAR")).unwrap(),
+            Some(NearToken::from_near(1))
+        );
+        assert_eq!(option::deserialize(json!(null)).unwrap(), None);
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            super::vec::deserialize(json!([1, "2", "3 yoctoNEAR"])).unwrap(),
+            [1, 2, 3].map(NearToken::from_yoctonear)
+        );
+    }
+
+    #[test]
+    fn json_permissive_errors() {
+        let expected =
+            "expected an integer amount of yoctoNEAR, a string with an amount of yoctoNEAR, \
+                        or a string with an amount and its unit such as \"1.5 NEAR\"";
+        for (value, error) in [
+            (json!(-1), "invalid value: integer `-1`, ".to_owned()),
+            (
+                json!(1.5),
+                "invalid type: floating point `1.5`, ".to_owned(),
+            ),
+            (json!(true), "invalid type: boolean `true`, ".to_owned()),
+            (json!([]), "invalid type: sequence, ".to_owned()),
+            (json!({}), "invalid type: map, ".to_owned()),
+            (json!({ "amount": "1" }), "invalid type: map, ".to_owned()),
+            (
+                json!(""),
+                "invalid amount \"\": missing token unit, ".to_owned(),
+            ),
+            (
+                json!("1.5"),
+                "invalid amount \"1.5\": missing token unit, ".to_owned(),
+            ),
+            (
+                json!(format!("{}0", u128::MAX)),
+                format!(
+                    "invalid amount \"{}0\": too large amount of yoctoNEAR, ",
+                    u128::MAX
+                ),
+            ),
+        ] {
+            assert_eq!(
+                deserialize(value.clone()).unwrap_err().to_string(),
+                error + expected,
+                "{}",
+                value
+            );
+        }
+        // Without the `arbitrary_precision` feature of `serde_json`, numbers beyond 64 bits are floating point ones.
+        for err in [
+            deserialize(serde_json::from_str::<serde_json::Value>("18446744073709551616").unwrap()),
+            deserialize(&mut serde_json::Deserializer::from_str(
+                "18446744073709551616",
+            )),
+        ] {
+            let err = err.unwrap_err().to_string();
+            assert!(err.starts_with("invalid type: floating point `"), "{}", err);
+            assert!(err.contains(expected), "{}", err);
+        }
+    }
+
+    #[test]
+    fn bincode_permissive_unsupported() {
+        let bytes = bincode::serialize("1000").unwrap();
+        let mut deserializer =
+            bincode::Deserializer::from_slice(&bytes, bincode::DefaultOptions::new());
+        assert!(deserialize(&mut deserializer).is_err());
+    }
+}
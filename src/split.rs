@@ -0,0 +1,184 @@
+use alloc::vec::Vec;
+
+use crate::{NearToken, SplitError};
+
+impl NearToken {
+    /// Splits the amount between recipients proportionally to their weights, so that the parts
+    /// always sum up exactly to the original amount.
+    ///
+    /// Every recipient gets its share rounded down, and the leftover yoctoNEAR are handed out one by one
+    /// to the recipients with the largest rounding remainders (largest remainder method).
+    /// Ties are resolved in favor of the recipient that comes first in `weights`.
+    /// A recipient with zero weight never receives anything.
+    ///
+    /// Returns `SplitError::ZeroTotalWeight` if `weights` is empty or all weights are zero,
+    /// and `SplitError::TotalWeightOverflow` if the sum of weights does not fit into `u128`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearToken;
+    /// let parts = NearToken::from_yoctonear(10).split_proportionally(&[1, 1, 1]).unwrap();
+    /// assert_eq!(parts, vec![
+    ///     NearToken::from_yoctonear(4),
+    ///     NearToken::from_yoctonear(3),
+    ///     NearToken::from_yoctonear(3),
+    /// ]);
+    ///
+    /// let parts = NearToken::from_near(1).split_proportionally(&[3, 1]).unwrap();
+    /// assert_eq!(parts, vec![NearToken::from_millinear(750), NearToken::from_millinear(250)]);
+    /// ```
+    pub fn split_proportionally(self, weights: &[u128]) -> Result<Vec<NearToken>, SplitError> {
+        let total_weight = weights
+            .iter()
+            .try_fold(0u128, |total, weight| total.checked_add(*weight))
+            .ok_or(SplitError::TotalWeightOverflow)?;
+        if total_weight == 0 {
+            return Err(SplitError::ZeroTotalWeight);
+        }
+
+        let mut parts = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        let mut distributed = 0u128;
+        for (index, weight) in weights.iter().enumerate() {
+            // `weight <= total_weight`, so the share never exceeds the amount and cannot overflow.
+            let (share, remainder) =
+                crate::utils::mul_div_rem(self.as_yoctonear(), *weight, total_weight)
+                    .expect("a share of the amount always fits into u128");
+            distributed += share;
+            parts.push(share);
+            remainders.push((remainder, index));
+        }
+
+        // The leftover is less than the number of recipients with a non-zero remainder.
+        let leftover = self.as_yoctonear() - distributed;
+        remainders.sort_unstable_by(|(remainder_a, index_a), (remainder_b, index_b)| {
+            remainder_b.cmp(remainder_a).then(index_a.cmp(index_b))
+        });
+        for (_, index) in remainders.into_iter().take(leftover as usize) {
+            parts[index] += 1;
+        }
+
+        Ok(parts.into_iter().map(NearToken::from_yoctonear).collect())
+    }
+
+    /// Splits the amount into `n` equal parts, so that the parts always sum up exactly to the original amount.
+    ///
+    /// The leftover yoctoNEAR are handed out one by one starting from the first part.
+    ///
+    /// Returns `SplitError::ZeroParts` if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::{NearToken, SplitError};
+    /// let parts = NearToken::from_yoctonear(11).split_evenly(3).unwrap();
+    /// assert_eq!(parts, vec![
+    ///     NearToken::from_yoctonear(4),
+    ///     NearToken::from_yoctonear(4),
+    ///     NearToken::from_yoctonear(3),
+    /// ]);
+    /// assert_eq!(NearToken::from_near(1).split_evenly(0), Err(SplitError::ZeroParts));
+    /// ```
+    pub fn split_evenly(self, n: usize) -> Result<Vec<NearToken>, SplitError> {
+        let count = n as u128;
+        if count == 0 {
+            return Err(SplitError::ZeroParts);
+        }
+        let share = self.as_yoctonear() / count;
+        let leftover = self.as_yoctonear() % count;
+        Ok((0..count)
+            .map(|index| {
+                if index < leftover {
+                    NearToken::from_yoctonear(share + 1)
+                } else {
+                    NearToken::from_yoctonear(share)
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NearToken, SplitError};
+
+    #[test]
+    fn split_proportionally() {
+        let tokens = NearToken::from_yoctonear(100);
+        for (weights, expected) in [
+            (vec![1], vec![100]),
+            (vec![1, 2], vec![33, 67]),
+            (vec![1, 1, 1], vec![34, 33, 33]),
+            (vec![0, 1, 0], vec![0, 100, 0]),
+            (vec![3, 3, 1], vec![43, 43, 14]),
+            (vec![1, 1, 1, 1, 1, 1, 1], vec![15, 15, 14, 14, 14, 14, 14]),
+            (vec![u128::MAX - 1, 1], vec![100, 0]),
+        ] {
+            let parts = tokens.split_proportionally(&weights).unwrap();
+            assert_eq!(
+                parts,
+                expected
+                    .into_iter()
+                    .map(NearToken::from_yoctonear)
+                    .collect::<Vec<_>>(),
+                "weights: {:?}",
+                weights
+            );
+        }
+    }
+
+    #[test]
+    fn split_proportionally_sums_to_amount() {
+        let weights = [7, 13, 1, 0, 999, u64::MAX as u128, 5];
+        for tokens in [
+            NearToken::ZERO,
+            NearToken::from_yoctonear(1),
+            NearToken::from_near(1234),
+            NearToken::from_yoctonear(u128::MAX - 1),
+            NearToken::MAX,
+        ] {
+            let parts = tokens.split_proportionally(&weights).unwrap();
+            assert_eq!(parts.len(), weights.len());
+            assert_eq!(NearToken::checked_sum(parts.iter().copied()), Some(tokens));
+            assert_eq!(parts[3], NearToken::ZERO);
+        }
+    }
+
+    #[test]
+    fn split_proportionally_errors() {
+        let tokens = NearToken::from_near(1);
+        assert_eq!(
+            tokens.split_proportionally(&[]),
+            Err(SplitError::ZeroTotalWeight)
+        );
+        assert_eq!(
+            tokens.split_proportionally(&[0, 0]),
+            Err(SplitError::ZeroTotalWeight)
+        );
+        assert_eq!(
+            tokens.split_proportionally(&[u128::MAX, 1]),
+            Err(SplitError::TotalWeightOverflow)
+        );
+    }
+
+    #[test]
+    fn split_evenly() {
+        assert_eq!(
+            NearToken::MAX.split_evenly(2).unwrap(),
+            vec![
+                NearToken::from_yoctonear(u128::MAX / 2 + 1),
+                NearToken::from_yoctonear(u128::MAX / 2)
+            ]
+        );
+        assert_eq!(
+            NearToken::from_yoctonear(2).split_evenly(3).unwrap(),
+            NearToken::from_yoctonear(2)
+                .split_proportionally(&[1, 1, 1])
+                .unwrap()
+        );
+        assert_eq!(
+            NearToken::ZERO.split_evenly(2).unwrap(),
+            vec![NearToken::ZERO, NearToken::ZERO]
+        );
+        assert_eq!(NearToken::MAX.split_evenly(0), Err(SplitError::ZeroParts));
+    }
+}
@@ -1,8 +1,28 @@
+use crate::{NearToken, NearTokenDelta};
+
+/// Negative zero is rejected so that every delta has a single canonical encoding.
+impl borsh::BorshDeserialize for NearTokenDelta {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let negative = bool::deserialize_reader(reader)?;
+        let magnitude = NearToken::deserialize_reader(reader)?;
+        if !negative {
+            Ok(NearTokenDelta::positive(magnitude))
+        } else if !magnitude.is_zero() {
+            Ok(NearTokenDelta::negative(magnitude))
+        } else {
+            Err(borsh::io::Error::new(
+                borsh::io::ErrorKind::InvalidData,
+                "negative zero NearTokenDelta",
+            ))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use borsh::{to_vec, BorshDeserialize};
 
-    use crate::NearToken;
+    use crate::{NearToken, NearTokenDelta};
 
     #[test]
     fn borsh() {
@@ -24,4 +44,33 @@ mod test {
         test_borsh_ser(8, [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
         test_borsh_ser(0, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
     }
+
+    #[test]
+    fn borsh_delta() {
+        fn test_borsh_ser(delta: NearTokenDelta, expected_serialized_value: [u8; 17]) {
+            let ser = to_vec(&delta).unwrap();
+            assert_eq!(expected_serialized_value, ser.as_slice());
+            let de = NearTokenDelta::try_from_slice(&ser).unwrap();
+            assert_eq!(de, delta);
+        }
+
+        test_borsh_ser(
+            NearTokenDelta::MIN,
+            [
+                1, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
+            ],
+        );
+        test_borsh_ser(
+            NearTokenDelta::from_yoctonear(8),
+            [0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        );
+        test_borsh_ser(
+            NearTokenDelta::ZERO,
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        );
+
+        let mut negative_zero = [0u8; 17];
+        negative_zero[0] = 1;
+        assert!(NearTokenDelta::try_from_slice(&negative_zero).is_err());
+    }
 }
@@ -1,37 +1,57 @@
-use crate::{NearToken, ONE_MILLINEAR};
+use crate::{NearToken, NearTokenDelta, NearTokenFormatter, NearUnit};
 
-/// NearToken Display implementation rounds up the token amount to the relevant precision point.
+/// NearToken Display implementation uses [`NearTokenFormatter::compact`] preset, which rounds up the token amount to the relevant precision point.
 /// There are 4 breakpoints:
 /// 1. exactly 0 NEAR
 /// 2. <0.001 NEAR
 /// 3. 0.001 - 0.999 NEAR (uses 3 digits after the floating point)
 /// 4. >1 NEAR (uses 2 digits after the floating point)
-impl std::fmt::Display for NearToken {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if *self == NearToken::from_yoctonear(0) {
-            write!(f, "0 NEAR")
-        } else if *self < NearToken::from_millinear(1) {
-            write!(f, "<0.001 NEAR")
-        } else if *self <= NearToken::from_millinear(999) {
-            let millinear_rounded_up =
-                self.as_yoctonear().saturating_add(ONE_MILLINEAR - 1) / ONE_MILLINEAR;
-            write!(f, "0.{:03} NEAR", millinear_rounded_up)
-        } else {
-            let near_rounded_up =
-                self.as_yoctonear().saturating_add(10 * ONE_MILLINEAR - 1) / ONE_MILLINEAR / 10;
-            write!(
-                f,
-                "{}.{:02} NEAR",
-                near_rounded_up / 100,
-                near_rounded_up % 100
-            )
-        }
+///
+/// The standard formatting options are respected:
+/// * precision (`{:.6}`) sets the exact number of digits after the floating point
+///   (values below the smallest representable step are displayed as `<0.000001 NEAR`);
+/// * alternate flag (`{:#}`) displays the exact amount, the same as [`NearToken::exact_amount_display`];
+/// * plus flag (`{:+}`) prefixes the amount with `+`;
+/// * width, fill and alignment (`{:>20}`, `{:*^20}`) pad the whole amount including the unit, left-aligned by default.
+///
+/// # Examples
+/// ```
+/// use near_token::NearToken;
+/// let tokens = NearToken::from_yoctonear(1_234_567_000_000_000_000_000_000);
+/// assert_eq!(format!("{}", tokens), "1.24 NEAR");
+/// assert_eq!(format!("{:.6}", tokens), "1.234567 NEAR");
+/// assert_eq!(format!("{:#}", tokens), "1.234567 NEAR");
+/// assert_eq!(format!("{:>12}", tokens), "   1.24 NEAR");
+/// assert_eq!(format!("{:+}", tokens), "+1.24 NEAR");
+/// ```
+impl core::fmt::Display for NearToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&NearTokenFormatter::from_format_flags(f).format(*self), f)
+    }
+}
+
+/// NearTokenDelta Display implementation prefixes the rounded magnitude with its sign.
+///
+/// It respects the same formatting options as NearToken Display implementation.
+impl core::fmt::Display for NearTokenDelta {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(
+            &NearTokenFormatter::from_format_flags(f).format_delta(*self),
+            f,
+        )
+    }
+}
+
+/// NearUnit Display implementation writes the canonical name of the unit, padded to the requested width.
+impl core::fmt::Display for NearUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.name())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::NearToken;
+    use crate::{NearToken, NearTokenDelta, NearUnit};
 
     #[test]
     fn test_display() {
@@ -102,4 +122,99 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_delta_display() {
+        for (delta, expected_display) in [
+            (NearTokenDelta::ZERO, "0 NEAR"),
+            (NearTokenDelta::from_yoctonear(1), "+<0.001 NEAR"),
+            (NearTokenDelta::from_yoctonear(-1), "-<0.001 NEAR"),
+            (
+                NearTokenDelta::negative(NearToken::from_millinear(1500)),
+                "-1.50 NEAR",
+            ),
+            (
+                NearTokenDelta::positive(NearToken::from_millinear(200)),
+                "+0.200 NEAR",
+            ),
+        ] {
+            assert_eq!(delta.to_string(), expected_display);
+        }
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let tokens = NearToken::from_yoctonear(1_234_567_890_000_000_000_000_000);
+        assert_eq!(format!("{:.0}", tokens), "2 NEAR");
+        assert_eq!(format!("{:.1}", tokens), "1.3 NEAR");
+        assert_eq!(format!("{:.6}", tokens), "1.234568 NEAR");
+        assert_eq!(format!("{:.10}", tokens), "1.2345678900 NEAR");
+        assert_eq!(format!("{:.24}", tokens), "1.234567890000000000000000 NEAR");
+        assert_eq!(format!("{:.30}", tokens), "1.234567890000000000000000 NEAR");
+        assert_eq!(format!("{:.2}", NearToken::ZERO), "0.00 NEAR");
+        assert_eq!(format!("{:.0}", NearToken::ZERO), "0 NEAR");
+        assert_eq!(format!("{:.2}", NearToken::from_yoctonear(1)), "<0.01 NEAR");
+        assert_eq!(format!("{:.0}", NearToken::from_yoctonear(1)), "<1 NEAR");
+        assert_eq!(format!("{:.2}", NearToken::from_millinear(10)), "0.01 NEAR");
+        assert_eq!(format!("{:.3}", NearToken::MAX), "340282366920938.464 NEAR");
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        for (near_tokens, expected_display) in [
+            (NearToken::from_yoctonear(0), "0 NEAR"),
+            (NearToken::from_yoctonear(500), "500 yoctoNEAR"),
+            (
+                NearToken::from_yoctonear(1001),
+                "0.000000000000000000001001 NEAR",
+            ),
+            (NearToken::from_near(1), "1 NEAR"),
+            (
+                NearToken::from_yoctonear(1_234_567_890_123_456_789_000_000),
+                "1.234567890123456789 NEAR",
+            ),
+            (
+                NearToken::MAX,
+                "340282366920938.463463374607431768211455 NEAR",
+            ),
+        ] {
+            assert_eq!(format!("{:#}", near_tokens), expected_display);
+            #[cfg(feature = "alloc")]
+            assert_eq!(near_tokens.exact_amount_display(), expected_display);
+        }
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let tokens = NearToken::from_millinear(1500);
+        assert_eq!(format!("{:12}|", tokens), "1.50 NEAR   |");
+        assert_eq!(format!("{:<12}|", tokens), "1.50 NEAR   |");
+        assert_eq!(format!("{:>12}|", tokens), "   1.50 NEAR|");
+        assert_eq!(format!("{:^12}|", tokens), " 1.50 NEAR  |");
+        assert_eq!(format!("{:*>12}|", tokens), "***1.50 NEAR|");
+        assert_eq!(format!("{:>4}|", tokens), "1.50 NEAR|");
+        assert_eq!(format!("{:+>12.1}|", tokens), "++++1.5 NEAR|");
+        assert_eq!(format!("{:>+12.1}|", tokens), "   +1.5 NEAR|");
+        assert_eq!(format!("{:>#16}|", tokens), "        1.5 NEAR|");
+        assert_eq!(format!("{:+}", NearToken::ZERO), "+0 NEAR");
+    }
+
+    #[test]
+    fn test_delta_display_options() {
+        let delta = NearTokenDelta::negative(NearToken::from_millinear(1500));
+        assert_eq!(format!("{:>12}|", delta), "  -1.50 NEAR|");
+        assert_eq!(format!("{:.4}", delta), "-1.5000 NEAR");
+        assert_eq!(format!("{:#}", delta), "-1.5 NEAR");
+        assert_eq!(
+            format!("{:+.1}", NearTokenDelta::positive(NearToken::from_near(2))),
+            "+2.0 NEAR"
+        );
+    }
+
+    #[test]
+    fn test_unit_display() {
+        assert_eq!(NearUnit::MilliNear.to_string(), "milliNEAR");
+        assert_eq!(format!("{:>6}|", NearUnit::Near), "  NEAR|");
+        assert_eq!(format!("{:<10}|", NearUnit::KiloNear), "kiloNEAR  |");
+    }
 }
@@ -1,32 +1,81 @@
-use crate::{NearToken, NearTokenError, ONE_MICRONEAR, ONE_MILLINEAR, ONE_NEAR};
+#[cfg(feature = "alloc")]
+use crate::parse::find_unit;
+#[cfg(feature = "alloc")]
+use crate::utils::decimal_number_error;
+#[cfg(feature = "alloc")]
+use crate::{NearToken, NearTokenDelta, NearTokenError};
+use crate::{NearUnit, ParseError};
 
-impl std::str::FromStr for NearToken {
+/// NearToken is parsed from an amount followed by a unit, see [`NearUnit`] for the supported units.
+///
+/// Use [`NearToken::parse_with_details`] to locate the offending part of an invalid input.
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for NearToken {
     type Err = NearTokenError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let uppercase_s = s.trim().to_ascii_uppercase();
-        let (value, unit) = uppercase_s.split_at(
-            s.find(|c: char| c.is_ascii_alphabetic())
-                .ok_or_else(|| NearTokenError::InvalidTokenUnit(s.to_owned()))?,
-        );
-        let unit_precision = match unit {
-            "YN" | "YNEAR" | "YOCTONEAR" => 1,
-            "MICRONEAR" => ONE_MICRONEAR,
-            "MILLINEAR" => ONE_MILLINEAR,
-            "NEAR" | "N" => ONE_NEAR,
-            _ => return Err(NearTokenError::InvalidTokenUnit(s.to_owned())),
+        NearToken::parse_const(s).map_err(|err| match err {
+            ParseError::MissingUnit | ParseError::InvalidUnit => {
+                NearTokenError::InvalidTokenUnit(s.into())
+            }
+            ParseError::InvalidNumber
+            | ParseError::InvalidDigitGrouping
+            | ParseError::LongWhole
+            | ParseError::LongFractional
+            | ParseError::ExponentOverflow => {
+                let trimmed = s.trim();
+                let value = &trimmed[..find_unit(trimmed.as_bytes(), 0, trimmed.len())];
+                NearTokenError::InvalidTokensAmount(decimal_number_error(err, value.trim()))
+            }
+        })
+    }
+}
+
+/// NearTokenDelta accepts the same amounts as NearToken with an optional leading `+` or `-` sign.
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for NearTokenDelta {
+    type Err = NearTokenError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let (negative, magnitude) = match trimmed.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
         };
-        Ok(NearToken::from_yoctonear(
-            crate::utils::parse_decimal_number(value.trim(), unit_precision)
-                .map_err(NearTokenError::InvalidTokensAmount)?,
-        ))
+        // the sign must be immediately followed by the digits of the amount
+        if magnitude.starts_with(|c: char| c == '+' || c == '-' || c.is_whitespace()) {
+            let value = &magnitude[..find_unit(magnitude.as_bytes(), 0, magnitude.len())];
+            return Err(NearTokenError::InvalidTokensAmount(decimal_number_error(
+                ParseError::InvalidNumber,
+                value.trim_end(),
+            )));
+        }
+        let magnitude = NearToken::from_str(magnitude)?;
+        Ok(if negative {
+            NearTokenDelta::negative(magnitude)
+        } else {
+            NearTokenDelta::positive(magnitude)
+        })
     }
 }
 
-#[cfg(test)]
+/// NearUnit is parsed from its canonical name or any of its aliases in any letter case, see [`NearUnit::from_name`].
+impl core::str::FromStr for NearUnit {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseError::MissingUnit);
+        }
+        NearUnit::from_name(s).ok_or(ParseError::InvalidUnit)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod test {
     use std::str::FromStr;
 
-    use crate::{DecimalNumberParsingError, NearToken, NearTokenError};
+    use crate::{
+        DecimalNumberParsingError, NearToken, NearTokenDelta, NearTokenError, NearUnit, ParseError,
+    };
 
     #[test]
     fn parse_decimal_number() {
@@ -166,4 +215,101 @@ mod test {
             ))
         );
     }
+
+    #[test]
+    fn parse_case_and_whitespace() {
+        assert_eq!(
+            NearToken::from_str("  1.5 Near ").unwrap(),
+            NearToken::from_millinear(1500)
+        );
+        assert_eq!(
+            NearToken::from_str("\t7 yoctonear").unwrap(),
+            NearToken::from_yoctonear(7)
+        );
+        assert_eq!(
+            NearToken::from_str("3 nEaRs").unwrap_err(),
+            NearTokenError::InvalidTokenUnit("3 nEaRs".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_scientific() {
+        assert_eq!(
+            NearToken::from_str("1e24 yoctoNEAR").unwrap(),
+            NearToken::from_near(1)
+        );
+        assert_eq!(
+            NearToken::from_str("2.5e-3 NEAR").unwrap(),
+            NearToken::from_micronear(2500)
+        );
+        assert_eq!(
+            NearToken::from_str("1e40 NEAR").unwrap_err(),
+            NearTokenError::InvalidTokensAmount(DecimalNumberParsingError::LongWhole(
+                "1e40".to_string()
+            ))
+        );
+        assert_eq!(
+            NearToken::from_str("2.5e-25 NEAR").unwrap_err(),
+            NearTokenError::InvalidTokensAmount(DecimalNumberParsingError::LongFractional(
+                "2.5e-25".to_string()
+            ))
+        );
+        assert_eq!(
+            NearTokenDelta::from_str("-1.5e3 milliNEAR").unwrap(),
+            NearTokenDelta::negative(NearToken::from_millinear(1500))
+        );
+    }
+
+    #[test]
+    fn parse_delta() {
+        for (data, expected) in [
+            ("-3 NEAR", NearTokenDelta::negative(NearToken::from_near(3))),
+            (
+                "+1.5 near",
+                NearTokenDelta::positive(NearToken::from_millinear(1500)),
+            ),
+            (
+                "2 milliNEAR",
+                NearTokenDelta::positive(NearToken::from_millinear(2)),
+            ),
+            (" -0 yoctoNEAR ", NearTokenDelta::ZERO),
+        ] {
+            assert_eq!(NearTokenDelta::from_str(data), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn parse_delta_invalid() {
+        assert_eq!(
+            NearTokenDelta::from_str("--1 NEAR"),
+            Err(NearTokenError::InvalidTokensAmount(
+                DecimalNumberParsingError::InvalidNumber("-1".to_owned())
+            ))
+        );
+        for (data, value) in [("-+1 NEAR", "+1"), ("- 3 NEAR", " 3"), ("+-1", "-1")] {
+            assert_eq!(
+                NearTokenDelta::from_str(data),
+                Err(NearTokenError::InvalidTokensAmount(
+                    DecimalNumberParsingError::InvalidNumber(value.to_owned())
+                )),
+                "{}",
+                data
+            );
+        }
+        assert_eq!(
+            NearTokenDelta::from_str("+1 UAH"),
+            Err(NearTokenError::InvalidTokenUnit("1 UAH".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_unit() {
+        assert_eq!(NearUnit::from_str(" megaNEAR "), Ok(NearUnit::MegaNear));
+        assert_eq!(NearUnit::from_str("yn"), Ok(NearUnit::YoctoNear));
+        assert_eq!(NearUnit::from_str(""), Err(ParseError::MissingUnit));
+        assert_eq!(NearUnit::from_str("1 NEAR"), Err(ParseError::InvalidUnit));
+        for unit in NearUnit::iter() {
+            assert_eq!(unit.to_string().parse(), Ok(unit));
+        }
+    }
 }
@@ -4,7 +4,9 @@ mod display;
 mod from_str;
 #[cfg(feature = "interactive-clap")]
 mod interactive_clap;
+mod ops;
 #[cfg(any(feature = "schemars-v0_8", feature = "schemars-v1"))]
-mod schemars;
+pub(crate) mod schemars;
 #[cfg(feature = "serde")]
 mod serde;
+mod sum;
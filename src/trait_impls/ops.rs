@@ -0,0 +1,168 @@
+//! Arithmetic operators for `NearToken`.
+//!
+//! All operators panic on overflow (and division by zero) regardless of the build profile,
+//! use `checked_*`, `saturating_*`, `wrapping_*` or `overflowing_*` methods to handle those cases explicitly.
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+
+use crate::NearToken;
+
+impl Add for NearToken {
+    type Output = NearToken;
+
+    fn add(self, rhs: NearToken) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("attempt to add NearToken with overflow")
+    }
+}
+
+impl AddAssign for NearToken {
+    fn add_assign(&mut self, rhs: NearToken) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for NearToken {
+    type Output = NearToken;
+
+    fn sub(self, rhs: NearToken) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract NearToken with overflow")
+    }
+}
+
+impl SubAssign for NearToken {
+    fn sub_assign(&mut self, rhs: NearToken) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u128> for NearToken {
+    type Output = NearToken;
+
+    fn mul(self, rhs: u128) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply NearToken with overflow")
+    }
+}
+
+impl MulAssign<u128> for NearToken {
+    fn mul_assign(&mut self, rhs: u128) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u128> for NearToken {
+    type Output = NearToken;
+
+    fn div(self, rhs: u128) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("attempt to divide NearToken by zero")
+    }
+}
+
+impl DivAssign<u128> for NearToken {
+    fn div_assign(&mut self, rhs: u128) {
+        *self = *self / rhs;
+    }
+}
+
+impl Rem<u128> for NearToken {
+    type Output = NearToken;
+
+    fn rem(self, rhs: u128) -> Self::Output {
+        self.checked_rem(rhs)
+            .expect("attempt to calculate the remainder of NearToken with a divisor of zero")
+    }
+}
+
+impl RemAssign<u128> for NearToken {
+    fn rem_assign(&mut self, rhs: u128) {
+        *self = *self % rhs;
+    }
+}
+
+/// Computes how many times `rhs` fits into `self`, e.g. the ratio between two balances.
+impl Div<NearToken> for NearToken {
+    type Output = u128;
+
+    fn div(self, rhs: NearToken) -> Self::Output {
+        self.as_yoctonear()
+            .checked_div(rhs.as_yoctonear())
+            .expect("attempt to divide NearToken by zero NearToken")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NearToken;
+
+    #[test]
+    fn add_sub() {
+        let mut tokens = NearToken::from_near(1) + NearToken::from_millinear(500);
+        assert_eq!(tokens, NearToken::from_millinear(1500));
+        tokens -= NearToken::from_millinear(1000);
+        assert_eq!(tokens, NearToken::from_millinear(500));
+        tokens += NearToken::from_millinear(250);
+        assert_eq!(tokens, NearToken::from_millinear(750));
+        assert_eq!(tokens - tokens, NearToken::ZERO);
+    }
+
+    #[test]
+    fn mul_div_rem() {
+        let mut tokens = NearToken::from_yoctonear(10) * 3;
+        assert_eq!(tokens, NearToken::from_yoctonear(30));
+        assert_eq!(tokens / 4, NearToken::from_yoctonear(7));
+        assert_eq!(tokens % 4, NearToken::from_yoctonear(2));
+        tokens *= 2;
+        assert_eq!(tokens, NearToken::from_yoctonear(60));
+        tokens /= 7;
+        assert_eq!(tokens, NearToken::from_yoctonear(8));
+        tokens %= 5;
+        assert_eq!(tokens, NearToken::from_yoctonear(3));
+    }
+
+    #[test]
+    fn div_by_tokens() {
+        assert_eq!(NearToken::from_near(10) / NearToken::from_near(3), 3);
+        assert_eq!(NearToken::from_near(1) / NearToken::from_near(2), 0);
+        assert_eq!(NearToken::MAX / NearToken::from_yoctonear(1), u128::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add NearToken with overflow")]
+    fn add_overflow() {
+        let _ = NearToken::MAX + NearToken::from_yoctonear(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract NearToken with overflow")]
+    fn sub_overflow() {
+        let _ = NearToken::ZERO - NearToken::from_yoctonear(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply NearToken with overflow")]
+    fn mul_overflow() {
+        let _ = NearToken::MAX * 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide NearToken by zero")]
+    fn div_by_zero() {
+        let _ = NearToken::from_near(1) / 0;
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "attempt to calculate the remainder of NearToken with a divisor of zero"
+    )]
+    fn rem_by_zero() {
+        let _ = NearToken::from_near(1) % 0;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide NearToken by zero NearToken")]
+    fn div_by_zero_tokens() {
+        let _ = NearToken::from_near(1) / NearToken::ZERO;
+    }
+}
@@ -1,4 +1,46 @@
-use crate::NearToken;
+use crate::{NearToken, NearTokenDelta};
+
+/// Description of the string with the amount of yoctoNEAR that `NearToken` is serialized as.
+const NEAR_TOKEN_DESCRIPTION: &str =
+    "Amount of NEAR tokens in yoctoNEAR (10^-24 NEAR) as a string of decimal digits";
+
+/// Description of the string with the signed amount of yoctoNEAR that `NearTokenDelta` is serialized as.
+const NEAR_TOKEN_DELTA_DESCRIPTION: &str =
+    "Signed change of an amount of NEAR tokens in yoctoNEAR (10^-24 NEAR) \
+     as a string of decimal digits with an optional minus sign";
+
+/// JSON schema of the string with the amount of yoctoNEAR that `NearToken` is serialized as, for schemars 0.8.
+#[cfg(feature = "schemars-v0_8")]
+pub(crate) fn near_token_schema_v0_8() -> schemars_v0_8::schema::Schema {
+    use schemars_v0_8::schema::{InstanceType, Metadata, SchemaObject, StringValidation};
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        format: Some("uint128".to_string()),
+        metadata: Some(Box::new(Metadata {
+            description: Some(NEAR_TOKEN_DESCRIPTION.to_string()),
+            examples: vec!["1000000000000000000000000".into(), "0".into()],
+            ..Default::default()
+        })),
+        string: Some(Box::new(StringValidation {
+            pattern: Some("^[0-9]{1,39}$".to_string()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
+/// JSON schema of the string with the amount of yoctoNEAR that `NearToken` is serialized as, for schemars 1.
+#[cfg(feature = "schemars-v1")]
+pub(crate) fn near_token_schema_v1() -> schemars_v1::Schema {
+    schemars_v1::json_schema!({
+        "type": "string",
+        "format": "uint128",
+        "pattern": "^[0-9]{1,39}$",
+        "description": NEAR_TOKEN_DESCRIPTION,
+        "examples": ["1000000000000000000000000", "0"],
+    })
+}
 
 #[cfg(feature = "schemars-v0_8")]
 impl schemars_v0_8::JsonSchema for NearToken {
@@ -11,11 +53,7 @@ impl schemars_v0_8::JsonSchema for NearToken {
     }
 
     fn json_schema(_: &mut schemars_v0_8::gen::SchemaGenerator) -> schemars_v0_8::schema::Schema {
-        use schemars_v0_8::schema::{InstanceType, Schema, SchemaObject, SingleOrVec};
-        Schema::Object(SchemaObject {
-            instance_type: Some(SingleOrVec::Single(Box::new(InstanceType::String))),
-            ..Default::default()
-        })
+        near_token_schema_v0_8()
     }
 }
 
@@ -25,16 +63,59 @@ impl schemars_v1::JsonSchema for NearToken {
         "NearToken".to_string().into()
     }
 
+    fn json_schema(_: &mut schemars_v1::SchemaGenerator) -> schemars_v1::Schema {
+        near_token_schema_v1()
+    }
+}
+
+#[cfg(feature = "schemars-v0_8")]
+impl schemars_v0_8::JsonSchema for NearTokenDelta {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "NearTokenDelta".to_string()
+    }
+
+    fn json_schema(_: &mut schemars_v0_8::gen::SchemaGenerator) -> schemars_v0_8::schema::Schema {
+        use schemars_v0_8::schema::{InstanceType, Metadata, SchemaObject, StringValidation};
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some(NEAR_TOKEN_DELTA_DESCRIPTION.to_string()),
+                examples: vec!["-1000000000000000000000000".into(), "1000".into()],
+                ..Default::default()
+            })),
+            string: Some(Box::new(StringValidation {
+                pattern: Some("^-?[0-9]{1,39}$".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "schemars-v1")]
+impl schemars_v1::JsonSchema for NearTokenDelta {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "NearTokenDelta".to_string().into()
+    }
+
     fn json_schema(_: &mut schemars_v1::SchemaGenerator) -> schemars_v1::Schema {
         schemars_v1::json_schema!({
             "type": "string",
+            "pattern": "^-?[0-9]{1,39}$",
+            "description": NEAR_TOKEN_DELTA_DESCRIPTION,
+            "examples": ["-1000000000000000000000000", "1000"],
         })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::NearToken;
+    use crate::{NearToken, NearTokenDelta};
     use serde_json::json;
 
     #[test]
@@ -44,8 +125,20 @@ mod test {
         let schema_json = serde_json::to_value(&root.schema).unwrap();
         assert_eq!(
             schema_json,
-            json!({ "title": "NearToken", "type": "string" })
+            json!({
+                "title": "NearToken",
+                "type": "string",
+                "format": "uint128",
+                "pattern": "^[0-9]{1,39}$",
+                "description": "Amount of NEAR tokens in yoctoNEAR (10^-24 NEAR) as a string of decimal digits",
+                "examples": ["1000000000000000000000000", "0"],
+            })
         );
+
+        let root = schemars_v0_8::schema_for!(NearTokenDelta);
+        let schema_json = serde_json::to_value(&root.schema).unwrap();
+        assert_eq!(schema_json["title"], "NearTokenDelta");
+        assert_eq!(schema_json["pattern"], "^-?[0-9]{1,39}$");
     }
 
     #[test]
@@ -55,7 +148,20 @@ mod test {
         let schema_json = serde_json::to_value(&root).unwrap();
         assert_eq!(
             schema_json,
-            json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "title": "NearToken", "type": "string" })
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "NearToken",
+                "type": "string",
+                "format": "uint128",
+                "pattern": "^[0-9]{1,39}$",
+                "description": "Amount of NEAR tokens in yoctoNEAR (10^-24 NEAR) as a string of decimal digits",
+                "examples": ["1000000000000000000000000", "0"],
+            })
         );
+
+        let root = schemars_v1::schema_for!(NearTokenDelta);
+        let schema_json = serde_json::to_value(&root).unwrap();
+        assert_eq!(schema_json["title"], "NearTokenDelta");
+        assert_eq!(schema_json["pattern"], "^-?[0-9]{1,39}$");
     }
 }
@@ -1,26 +1,30 @@
+use core::fmt::Write;
+
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::NearToken;
+use crate::utils::StackBuffer;
+use crate::{NearToken, NearTokenDelta};
 
+/// NearToken is serialized as a string with the amount of yoctoNEAR, e.g. `"1000"`, in human-readable formats
+/// such as JSON, and as a native u128 in binary formats such as bincode, i.e. 16 little-endian bytes there.
+///
+/// Versions up to 0.3.3 used the string in binary formats as well. Such data is not decoded correctly
+/// by this implementation, so fields holding it have to be annotated with
+/// `#[serde(with = "near_token::serde::as_yocto_str")]` to keep the string representation.
 impl Serialize for NearToken {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         use serde::ser::Error;
-        let mut buf = [0u8; 40];
-        let remainder = {
-            use std::io::Write;
-            let mut w: &mut [u8] = &mut buf;
-            write!(w, "{}", self.inner)
-                .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
-            w.len()
-        };
-        let len = buf.len() - remainder;
-
-        let s = std::str::from_utf8(&buf[..len])
-            .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
-        serializer.serialize_str(s)
+        if !serializer.is_human_readable() {
+            return serializer.serialize_u128(self.inner);
+        }
+        // u128::MAX has 39 decimal digits
+        let mut buf = StackBuffer::<39>::new();
+        write!(buf, "{}", self.inner)
+            .map_err(|err| Error::custom(format_args!("Failed to serialize: {}", err)))?;
+        serializer.serialize_str(buf.as_str())
     }
 }
 
@@ -29,16 +33,229 @@ impl<'de> Deserialize<'de> for NearToken {
     where
         D: Deserializer<'de>,
     {
-        let s: String = Deserialize::deserialize(deserializer)?;
-        s.parse::<u128>()
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(NearTokenVisitor)
+        } else {
+            deserializer.deserialize_u128(NearTokenVisitor)
+        }
+    }
+}
+
+/// NearTokenDelta is serialized as a signed token-precision number encoded as string, e.g. `"-1000"`,
+/// in human-readable formats, and as a pair of the sign and the u128 magnitude in binary formats,
+/// the same layout as in borsh.
+impl Serialize for NearTokenDelta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::{Error, SerializeTuple};
+        if !serializer.is_human_readable() {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.is_negative())?;
+            tuple.serialize_element(&self.magnitude().as_yoctonear())?;
+            return tuple.end();
+        }
+        // the sign and 39 decimal digits of u128::MAX
+        let mut buf = StackBuffer::<40>::new();
+        if self.is_negative() {
+            buf.write_char('-')
+                .map_err(|err| Error::custom(format_args!("Failed to serialize: {}", err)))?;
+        }
+        write!(buf, "{}", self.magnitude().as_yoctonear())
+            .map_err(|err| Error::custom(format_args!("Failed to serialize: {}", err)))?;
+        serializer.serialize_str(buf.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for NearTokenDelta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(NearTokenDeltaVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, CompactDeltaVisitor)
+        }
+    }
+}
+
+/// Parses the decimal digits of an amount of yoctoNEAR without a sign, or returns None if there are no digits,
+/// any other characters or the amount overflows.
+fn parse_yocto(digits: &[u8]) -> Option<u128> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut value: u128 = 0;
+    for &digit in digits {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        value = value
+            .checked_mul(10)?
+            .checked_add(u128::from(digit - b'0'))?;
+    }
+    Some(value)
+}
+
+/// Visitors borrow the string or bytes from the deserializer, so no allocation is needed to parse them.
+struct NearTokenVisitor;
+
+impl NearTokenVisitor {
+    fn parse<E: de::Error>(
+        self,
+        digits: &[u8],
+        unexpected: de::Unexpected,
+    ) -> Result<NearToken, E> {
+        // a leading plus sign is accepted, as by `u128::from_str`
+        let digits = digits.strip_prefix(b"+").unwrap_or(digits);
+        parse_yocto(digits)
             .map(NearToken::from_yoctonear)
-            .map_err(|err| de::Error::custom(err.to_string()))
+            .ok_or_else(|| de::Error::invalid_value(unexpected, &self))
+    }
+}
+
+impl<'de> de::Visitor<'de> for NearTokenVisitor {
+    type Value = NearToken;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter
+            .write_str("a string of decimal digits with an amount of yoctoNEAR up to 2^128 - 1")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(NearToken::from_yoctonear(value.into()))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        Ok(NearToken::from_yoctonear(value))
+    }
+
+    // Formats such as TOML have signed integers only.
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u128::try_from(value)
+            .map(NearToken::from_yoctonear)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+        u128::try_from(value)
+            .map(NearToken::from_yoctonear)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Other("negative integer"), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        self.parse(s.as_bytes(), de::Unexpected::Str(s))
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        self.parse(bytes, de::Unexpected::Bytes(bytes))
+    }
+}
+
+struct NearTokenDeltaVisitor;
+
+impl NearTokenDeltaVisitor {
+    fn parse<E: de::Error>(
+        self,
+        digits: &[u8],
+        unexpected: de::Unexpected,
+    ) -> Result<NearTokenDelta, E> {
+        let (negative, magnitude) = match digits.split_first() {
+            Some((b'-', magnitude)) => (true, magnitude),
+            Some((b'+', magnitude)) => (false, magnitude),
+            _ => (false, digits),
+        };
+        let magnitude = parse_yocto(magnitude)
+            .map(NearToken::from_yoctonear)
+            .ok_or_else(|| de::Error::invalid_value(unexpected, &self))?;
+        Ok(if negative {
+            NearTokenDelta::negative(magnitude)
+        } else {
+            NearTokenDelta::positive(magnitude)
+        })
+    }
+}
+
+impl<'de> de::Visitor<'de> for NearTokenDeltaVisitor {
+    type Value = NearTokenDelta;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(
+            "a string of decimal digits with an optional sign \
+             and a signed amount of yoctoNEAR up to 2^128 - 1 in magnitude",
+        )
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(NearTokenDelta::positive(NearToken::from_yoctonear(
+            value.into(),
+        )))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        Ok(NearTokenDelta::positive(NearToken::from_yoctonear(value)))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        self.visit_i128(value.into())
+    }
+
+    fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+        let magnitude = NearToken::from_yoctonear(value.unsigned_abs());
+        Ok(if value < 0 {
+            NearTokenDelta::negative(magnitude)
+        } else {
+            NearTokenDelta::positive(magnitude)
+        })
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        self.parse(s.as_bytes(), de::Unexpected::Str(s))
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        self.parse(bytes, de::Unexpected::Bytes(bytes))
+    }
+}
+
+/// Visitor of the binary representation of NearTokenDelta: the sign and the magnitude.
+///
+/// Negative zero is rejected, as in borsh, so that every delta has a single canonical encoding.
+struct CompactDeltaVisitor;
+
+impl<'de> de::Visitor<'de> for CompactDeltaVisitor {
+    type Value = NearTokenDelta;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a pair of the sign and the u128 magnitude of an amount of yoctoNEAR")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let negative: bool = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let magnitude: u128 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let magnitude = NearToken::from_yoctonear(magnitude);
+        if !negative {
+            Ok(NearTokenDelta::positive(magnitude))
+        } else if !magnitude.is_zero() {
+            Ok(NearTokenDelta::negative(magnitude))
+        } else {
+            Err(de::Error::invalid_value(
+                de::Unexpected::Other("negative zero"),
+                &self,
+            ))
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::NearToken;
+    use crate::{NearToken, NearTokenDelta};
 
     #[test]
     fn json_ser() {
@@ -54,4 +271,235 @@ mod test {
         test_json_ser(8);
         test_json_ser(0);
     }
+
+    #[test]
+    fn json_ser_delta() {
+        fn test_json_ser(delta: NearTokenDelta, expected: &str) {
+            let ser = serde_json::to_string(&delta).unwrap();
+            assert_eq!(ser, expected);
+            let de: NearTokenDelta = serde_json::from_str(&ser).unwrap();
+            assert_eq!(de, delta);
+        }
+
+        test_json_ser(NearTokenDelta::MAX, &format!("\"{}\"", u128::MAX));
+        test_json_ser(NearTokenDelta::MIN, &format!("\"-{}\"", u128::MAX));
+        test_json_ser(NearTokenDelta::from_yoctonear(-8), "\"-8\"");
+        test_json_ser(NearTokenDelta::ZERO, "\"0\"");
+
+        assert_eq!(
+            serde_json::from_str::<NearTokenDelta>("\"-0\"").unwrap(),
+            NearTokenDelta::ZERO
+        );
+        assert!(serde_json::from_str::<NearTokenDelta>("\"--1\"").is_err());
+        assert_eq!(
+            serde_json::from_str::<NearTokenDelta>("\"+5\"").unwrap(),
+            NearTokenDelta::from_yoctonear(5)
+        );
+        assert!(serde_json::from_str::<NearTokenDelta>("\"-+5\"").is_err());
+        assert!(serde_json::from_str::<NearTokenDelta>("\"+-5\"").is_err());
+        assert!(serde_json::from_str::<NearTokenDelta>("\"- 5\"").is_err());
+    }
+
+    #[test]
+    fn json_de_from_owned_and_invalid() {
+        let de: NearToken = serde_json::from_value(serde_json::json!("1000")).unwrap();
+        assert_eq!(de, NearToken::from_yoctonear(1000));
+        let de: NearTokenDelta = serde_json::from_reader("\"-1000\"".as_bytes()).unwrap();
+        assert_eq!(de, NearTokenDelta::from_yoctonear(-1000));
+
+        assert!(serde_json::from_str::<NearToken>("\"-1\"").is_err());
+        assert!(serde_json::from_str::<NearToken>("\"1 NEAR\"").is_err());
+        assert!(serde_json::from_str::<NearToken>(&format!("\"{}0\"", u128::MAX)).is_err());
+    }
+
+    #[test]
+    fn de_without_allocation() {
+        use serde::de::value::{BorrowedBytesDeserializer, BorrowedStrDeserializer, Error};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        fn de<'de, T: Deserialize<'de>>(
+            deserializer: impl serde::Deserializer<'de, Error = Error>,
+        ) -> Result<T, String> {
+            T::deserialize(deserializer).map_err(|err| err.to_string())
+        }
+
+        assert_eq!(
+            de::<NearToken>(BorrowedStrDeserializer::new("1000")),
+            Ok(NearToken::from_yoctonear(1000))
+        );
+        assert_eq!(
+            de::<NearToken>(BorrowedBytesDeserializer::new(b"1000")),
+            Ok(NearToken::from_yoctonear(1000))
+        );
+        assert_eq!(
+            de::<NearToken>(7u64.into_deserializer()),
+            Ok(NearToken::from_yoctonear(7))
+        );
+        assert_eq!(
+            de::<NearToken>(u128::MAX.into_deserializer()),
+            Ok(NearToken::MAX)
+        );
+        assert_eq!(
+            de::<NearToken>(7i64.into_deserializer()),
+            Ok(NearToken::from_yoctonear(7))
+        );
+        assert_eq!(
+            de::<NearToken>(i128::MAX.into_deserializer()),
+            Ok(NearToken::from_yoctonear(i128::MAX as u128))
+        );
+        assert_eq!(
+            de::<NearToken>(BorrowedStrDeserializer::new("+1")),
+            Ok(NearToken::from_yoctonear(1))
+        );
+        assert_eq!(
+            de::<NearTokenDelta>(BorrowedBytesDeserializer::new(b"-1000")),
+            Ok(NearTokenDelta::from_yoctonear(-1000))
+        );
+        assert_eq!(
+            de::<NearTokenDelta>((-7i64).into_deserializer()),
+            Ok(NearTokenDelta::from_yoctonear(-7))
+        );
+        assert_eq!(
+            de::<NearTokenDelta>(i128::MIN.into_deserializer()),
+            Ok(NearTokenDelta::from_yoctonear(i128::MIN))
+        );
+
+        let expected =
+            "expected a string of decimal digits with an amount of yoctoNEAR up to 2^128 - 1";
+        for (s, unexpected) in [
+            ("", "string \"\""),
+            ("+", "string \"+\""),
+            ("++1", "string \"++1\""),
+            ("+-1", "string \"+-1\""),
+            ("1 NEAR", "string \"1 NEAR\""),
+            (
+                "340282366920938463463374607431768211456",
+                "string \"340282366920938463463374607431768211456\"",
+            ),
+        ] {
+            assert_eq!(
+                de::<NearToken>(BorrowedStrDeserializer::new(s)),
+                Err(format!("invalid value: {}, {}", unexpected, expected))
+            );
+        }
+        assert_eq!(
+            de::<NearToken>(BorrowedBytesDeserializer::new(b"0x10")),
+            Err(format!("invalid value: byte array, {}", expected))
+        );
+        assert_eq!(
+            de::<NearToken>((-1i64).into_deserializer()),
+            Err(format!("invalid value: integer `-1`, {}", expected))
+        );
+        assert_eq!(
+            de::<NearToken>((-1i128).into_deserializer()),
+            Err(format!("invalid value: negative integer, {}", expected))
+        );
+        assert!(de::<NearTokenDelta>(BorrowedStrDeserializer::new("-")).is_err());
+        assert!(de::<NearTokenDelta>(BorrowedStrDeserializer::new("1-")).is_err());
+    }
+
+    #[test]
+    fn bincode_compact() {
+        fn round_trip<T>(value: T, expected: &[u8])
+        where
+            T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + core::fmt::Debug,
+        {
+            let bytes = bincode::serialize(&value).unwrap();
+            assert_eq!(bytes, expected);
+            assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+        }
+
+        round_trip(NearToken::ZERO, &[0; 16]);
+        round_trip(NearToken::from_yoctonear(0x0102), &{
+            let mut bytes = [0; 16];
+            bytes[..2].copy_from_slice(&[0x02, 0x01]);
+            bytes
+        });
+        round_trip(NearToken::MAX, &[0xff; 16]);
+        round_trip(NearToken::from_near(1), &10u128.pow(24).to_le_bytes());
+
+        round_trip(NearTokenDelta::ZERO, &[0; 17]);
+        round_trip(NearTokenDelta::from_yoctonear(-8), &{
+            let mut bytes = [0; 17];
+            bytes[..2].copy_from_slice(&[1, 8]);
+            bytes
+        });
+        round_trip(NearTokenDelta::MAX, &{
+            let mut bytes = [0xff; 17];
+            bytes[0] = 0;
+            bytes
+        });
+        round_trip(NearTokenDelta::MIN, &{
+            let mut bytes = [0xff; 17];
+            bytes[0] = 1;
+            bytes
+        });
+
+        // the human-readable representation is unchanged
+        assert_eq!(
+            serde_json::to_string(&NearToken::from_yoctonear(0x0102)).unwrap(),
+            "\"258\""
+        );
+        assert_eq!(
+            serde_json::to_string(&NearTokenDelta::from_yoctonear(-8)).unwrap(),
+            "\"-8\""
+        );
+
+        assert!(bincode::deserialize::<NearToken>(&[0; 15]).is_err());
+    }
+
+    #[test]
+    fn bincode_legacy_string() {
+        /// A field annotated with `#[serde(with = "near_token::serde::as_yocto_str")]`,
+        /// the migration path for data written in binary formats by 0.3.3 and earlier.
+        #[derive(Debug, PartialEq)]
+        struct Legacy(NearToken);
+
+        impl serde::Serialize for Legacy {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde::as_yocto_str::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for Legacy {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::serde::as_yocto_str::deserialize(deserializer).map(Legacy)
+            }
+        }
+
+        // the encoding of NearToken in bincode up to 0.3.3
+        for tokens in [NearToken::ZERO, NearToken::from_near(1), NearToken::MAX] {
+            let legacy = bincode::serialize(&tokens.as_yoctonear().to_string()).unwrap();
+            // the annotated field reads the stored data and writes exactly the same bytes back
+            assert_eq!(
+                bincode::deserialize::<Legacy>(&legacy).unwrap(),
+                Legacy(tokens)
+            );
+            assert_eq!(bincode::serialize(&Legacy(tokens)).unwrap(), legacy);
+            // and the amount read can be stored anew in the current representation
+            assert_eq!(
+                bincode::serialize(&tokens).unwrap(),
+                tokens.as_yoctonear().to_le_bytes()
+            );
+        }
+
+        let legacy = bincode::serialize("1000000000000000000000000").unwrap();
+        // the length prefix and the first digits are misread as the u128 amount
+        assert_ne!(
+            bincode::deserialize::<NearToken>(&legacy).unwrap(),
+            NearToken::from_near(1)
+        );
+        assert!(bincode::deserialize::<NearToken>(&bincode::serialize("1000").unwrap()).is_err());
+        assert!(bincode::deserialize::<NearTokenDelta>(&[2; 17]).is_err());
+        let mut negative_zero = [0; 17];
+        negative_zero[0] = 1;
+        assert_eq!(
+            bincode::deserialize::<NearTokenDelta>(&negative_zero)
+                .unwrap_err()
+                .to_string(),
+            "invalid value: negative zero, \
+             expected a pair of the sign and the u128 magnitude of an amount of yoctoNEAR"
+        );
+    }
 }
@@ -0,0 +1,46 @@
+//! Iterator aggregation for `NearToken`.
+//!
+//! Summing panics on overflow the same way as the `+` operator does,
+//! use `NearToken::checked_sum` or `NearToken::saturating_sum` to handle it explicitly.
+use core::iter::Sum;
+
+use crate::NearToken;
+
+impl Sum for NearToken {
+    fn sum<I: Iterator<Item = NearToken>>(iter: I) -> Self {
+        iter.fold(NearToken::ZERO, |sum, tokens| sum + tokens)
+    }
+}
+
+impl<'a> Sum<&'a NearToken> for NearToken {
+    fn sum<I: Iterator<Item = &'a NearToken>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NearToken;
+
+    #[test]
+    fn sum() {
+        let balances = vec![
+            NearToken::from_near(1),
+            NearToken::from_millinear(250),
+            NearToken::from_yoctonear(1),
+        ];
+        let expected = NearToken::from_yoctonear(1_250_000_000_000_000_000_000_001);
+        assert_eq!(balances.iter().sum::<NearToken>(), expected);
+        assert_eq!(balances.into_iter().sum::<NearToken>(), expected);
+        assert_eq!(
+            Vec::<NearToken>::new().into_iter().sum::<NearToken>(),
+            NearToken::ZERO
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add NearToken with overflow")]
+    fn sum_overflow() {
+        let _: NearToken = [NearToken::MAX, NearToken::from_yoctonear(1)].iter().sum();
+    }
+}
@@ -0,0 +1,308 @@
+/// Denomination in which a `NearToken` amount can be expressed.
+///
+/// Covers every SI prefix from yocto (10^-24 NEAR, the smallest indivisible amount) to mega (10^6 NEAR).
+/// Units are ordered by their size.
+///
+/// A unit is displayed with its canonical name and parsed from its name or any of its aliases in any letter case,
+/// the same way as the unit of a `NearToken` amount.
+///
+/// # Examples
+/// ```
+/// use near_token::NearUnit;
+///
+/// let unit: NearUnit = "kilonear".parse().unwrap();
+/// assert_eq!(unit, NearUnit::KiloNear);
+/// assert_eq!(unit.to_string(), "kiloNEAR");
+/// assert_eq!(unit.multiplier(), 10u128.pow(27));
+///
+/// let names: Vec<&str> = NearUnit::iter().map(NearUnit::name).collect();
+/// assert_eq!(names[..3], ["yoctoNEAR", "zeptoNEAR", "attoNEAR"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NearUnit {
+    /// 10^-24 NEAR, the smallest indivisible amount
+    YoctoNear,
+    /// 10^-21 NEAR
+    ZeptoNear,
+    /// 10^-18 NEAR
+    AttoNear,
+    /// 10^-15 NEAR
+    FemtoNear,
+    /// 10^-12 NEAR
+    PicoNear,
+    /// 10^-9 NEAR
+    NanoNear,
+    /// 10^-6 NEAR
+    MicroNear,
+    /// 10^-3 NEAR
+    MilliNear,
+    /// 10^-2 NEAR
+    CentiNear,
+    /// 10^-1 NEAR
+    DeciNear,
+    /// 1 NEAR
+    Near,
+    /// 10 NEAR
+    DekaNear,
+    /// 10^2 NEAR
+    HectoNear,
+    /// 10^3 NEAR
+    KiloNear,
+    /// 10^6 NEAR
+    MegaNear,
+}
+
+impl NearUnit {
+    /// Every unit, from the smallest to the largest.
+    pub const ALL: [NearUnit; 15] = [
+        NearUnit::YoctoNear,
+        NearUnit::ZeptoNear,
+        NearUnit::AttoNear,
+        NearUnit::FemtoNear,
+        NearUnit::PicoNear,
+        NearUnit::NanoNear,
+        NearUnit::MicroNear,
+        NearUnit::MilliNear,
+        NearUnit::CentiNear,
+        NearUnit::DeciNear,
+        NearUnit::Near,
+        NearUnit::DekaNear,
+        NearUnit::HectoNear,
+        NearUnit::KiloNear,
+        NearUnit::MegaNear,
+    ];
+
+    /// Returns an iterator over all units, from the smallest to the largest.
+    pub fn iter() -> impl DoubleEndedIterator<Item = NearUnit> + ExactSizeIterator {
+        Self::ALL.into_iter()
+    }
+
+    /// Finds the unit spelled by `name` in any letter case, accepting the canonical name and the aliases.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearUnit;
+    /// assert_eq!(NearUnit::from_name("yN"), Some(NearUnit::YoctoNear));
+    /// assert_eq!(NearUnit::from_name("MILLINEAR"), Some(NearUnit::MilliNear));
+    /// assert_eq!(NearUnit::from_name("Ⓝ"), Some(NearUnit::Near));
+    /// assert_eq!(NearUnit::from_name("gigaNEAR"), None);
+    /// ```
+    pub const fn from_name(name: &str) -> Option<NearUnit> {
+        Self::find(name.as_bytes(), 0, name.len())
+    }
+
+    /// Finds the unit spelled by `bytes[start..end]` in any letter case.
+    pub(crate) const fn find(bytes: &[u8], start: usize, end: usize) -> Option<NearUnit> {
+        let mut u = 0;
+        while u < Self::ALL.len() {
+            let unit = Self::ALL[u];
+            u += 1;
+            if eq_ignore_case(bytes, start, end, unit.name().as_bytes()) {
+                return Some(unit);
+            }
+            let aliases = unit.aliases();
+            let mut a = 0;
+            while a < aliases.len() {
+                if eq_ignore_case(bytes, start, end, aliases[a].as_bytes()) {
+                    return Some(unit);
+                }
+                a += 1;
+            }
+        }
+        None
+    }
+
+    /// Returns the unit whose name or alias is the closest to the misspelled `name`,
+    /// if it is close enough to be a typo.
+    pub(crate) fn closest(name: &str) -> Option<NearUnit> {
+        let mut closest: Option<(usize, NearUnit)> = None;
+        for unit in Self::ALL {
+            for candidate in [unit.name()].iter().chain(unit.aliases()) {
+                let Some(distance) = edit_distance(name, candidate) else {
+                    continue;
+                };
+                if distance <= MAX_TYPO_DISTANCE
+                    && distance < candidate.chars().count()
+                    && closest.map_or(true, |(closest, _)| distance < closest)
+                {
+                    closest = Some((distance, unit));
+                }
+            }
+        }
+        closest.map(|(_, unit)| unit)
+    }
+
+    /// Number of yoctoNEAR in one unit.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_token::NearUnit;
+    /// assert_eq!(NearUnit::MilliNear.multiplier(), 10u128.pow(21));
+    /// ```
+    pub const fn multiplier(self) -> u128 {
+        10u128.pow(self.decimals())
+    }
+
+    /// Number of fractional digits needed to express any amount in this unit exactly,
+    /// i.e. the power of ten of [`NearUnit::multiplier`].
+    pub const fn decimals(self) -> u32 {
+        match self {
+            NearUnit::YoctoNear => 0,
+            NearUnit::ZeptoNear => 3,
+            NearUnit::AttoNear => 6,
+            NearUnit::FemtoNear => 9,
+            NearUnit::PicoNear => 12,
+            NearUnit::NanoNear => 15,
+            NearUnit::MicroNear => 18,
+            NearUnit::MilliNear => 21,
+            NearUnit::CentiNear => 22,
+            NearUnit::DeciNear => 23,
+            NearUnit::Near => 24,
+            NearUnit::DekaNear => 25,
+            NearUnit::HectoNear => 26,
+            NearUnit::KiloNear => 27,
+            NearUnit::MegaNear => 30,
+        }
+    }
+
+    /// Canonical name used when displaying amounts in this unit, e.g. `milliNEAR`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            NearUnit::YoctoNear => "yoctoNEAR",
+            NearUnit::ZeptoNear => "zeptoNEAR",
+            NearUnit::AttoNear => "attoNEAR",
+            NearUnit::FemtoNear => "femtoNEAR",
+            NearUnit::PicoNear => "picoNEAR",
+            NearUnit::NanoNear => "nanoNEAR",
+            NearUnit::MicroNear => "microNEAR",
+            NearUnit::MilliNear => "milliNEAR",
+            NearUnit::CentiNear => "centiNEAR",
+            NearUnit::DeciNear => "deciNEAR",
+            NearUnit::Near => "NEAR",
+            NearUnit::DekaNear => "dekaNEAR",
+            NearUnit::HectoNear => "hectoNEAR",
+            NearUnit::KiloNear => "kiloNEAR",
+            NearUnit::MegaNear => "megaNEAR",
+        }
+    }
+
+    /// Spellings accepted by the parser in addition to the canonical name, e.g. `N` and `Ⓝ` for NEAR.
+    pub const fn aliases(self) -> &'static [&'static str] {
+        match self {
+            NearUnit::YoctoNear => &["yN", "yNEAR"],
+            NearUnit::Near => &["N", "Ⓝ"],
+            _ => &[],
+        }
+    }
+}
+
+/// Maximum number of edits between a misspelled unit and the suggested one.
+const MAX_TYPO_DISTANCE: usize = 2;
+
+/// Longest misspelled unit considered for suggestions, in characters.
+const MAX_TYPO_LEN: usize = 16;
+
+/// Returns the number of insertions, deletions, substitutions and transpositions of adjacent characters
+/// needed to turn `a` into `b` ignoring ASCII letter case, or None if any of them is too long.
+fn edit_distance(a: &str, b: &str) -> Option<usize> {
+    fn chars(s: &str) -> Option<([char; MAX_TYPO_LEN], usize)> {
+        let mut chars = ['\0'; MAX_TYPO_LEN];
+        let mut len = 0;
+        for c in s.chars() {
+            *chars.get_mut(len)? = c.to_ascii_lowercase();
+            len += 1;
+        }
+        Some((chars, len))
+    }
+    let (a, a_len) = chars(a)?;
+    let (b, b_len) = chars(b)?;
+    let mut distances = [[0; MAX_TYPO_LEN + 1]; MAX_TYPO_LEN + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a_len {
+        for j in 1..=b_len {
+            let substitution = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + substitution);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    Some(distances[a_len][b_len])
+}
+
+/// Checks whether `bytes[start..end]` equals `name` ignoring ASCII letter case.
+const fn eq_ignore_case(bytes: &[u8], start: usize, end: usize, name: &[u8]) -> bool {
+    if name.len() != end - start {
+        return false;
+    }
+    let mut i = 0;
+    while i < name.len() {
+        if bytes[start + i].to_ascii_uppercase() != name[i].to_ascii_uppercase() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::NearUnit;
+
+    #[test]
+    fn multiplier_matches_decimals() {
+        for unit in NearUnit::ALL {
+            assert_eq!(unit.multiplier(), 10u128.pow(unit.decimals()));
+        }
+        assert_eq!(NearUnit::Near.multiplier(), crate::ONE_NEAR);
+        assert_eq!(NearUnit::MilliNear.multiplier(), crate::ONE_MILLINEAR);
+        assert_eq!(NearUnit::MicroNear.multiplier(), crate::ONE_MICRONEAR);
+    }
+
+    #[test]
+    fn ordered_by_size() {
+        for pair in NearUnit::ALL.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].multiplier() < pair[1].multiplier());
+        }
+        assert!(NearUnit::iter().eq(NearUnit::ALL));
+        assert!(NearUnit::iter().rev().eq(NearUnit::ALL.into_iter().rev()));
+    }
+
+    #[test]
+    fn names_are_unique() {
+        for unit in NearUnit::ALL {
+            assert_eq!(NearUnit::from_name(unit.name()), Some(unit));
+            for alias in unit.aliases() {
+                assert_eq!(NearUnit::from_name(alias), Some(unit));
+            }
+        }
+        assert_eq!(NearUnit::from_name(""), None);
+        assert_eq!(NearUnit::from_name("NEAR "), None);
+    }
+
+    #[test]
+    fn closest_unit() {
+        for (typo, expected) in [
+            ("NAER", Some(NearUnit::Near)),
+            ("near", Some(NearUnit::Near)),
+            ("NE", Some(NearUnit::Near)),
+            ("milinear", Some(NearUnit::MilliNear)),
+            ("yoctonar", Some(NearUnit::YoctoNear)),
+            ("kilo-NEAR", Some(NearUnit::KiloNear)),
+            ("X", None),
+            ("UAH", None),
+            ("yoctoNEARyoctoNEAR", None),
+        ] {
+            assert_eq!(NearUnit::closest(typo), expected, "typo: {}", typo);
+        }
+    }
+}
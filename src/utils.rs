@@ -1,64 +1,128 @@
-/// Parsing decimal numbers from `&str` type in `u128`.
-/// Function also takes a value of metric prefix in u128 type.
-/// `parse_str` use the `u128` type, and have the same max and min values.
-///
-/// If the fractional part is longer than several zeros in the prefix, it will return the error `DecimalNumberParsingError::LongFractional`.
-///
-/// If the string slice has invalid chars, it will return the error `DecimalNumberParsingError::InvalidNumber`.
+#[cfg(feature = "alloc")]
+use crate::ParseError;
+
+/// Converts the error of parsing the decimal number `s` into `DecimalNumberParsingError`
+/// carrying the part of `s` that caused it.
+#[cfg(feature = "alloc")]
+pub(crate) fn decimal_number_error(err: ParseError, s: &str) -> DecimalNumberParsingError {
+    let trimmed = s.trim();
+    let (whole, fractional) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+    let scientific = trimmed.contains(['e', 'E']);
+    match err {
+        // The amount in exponent notation does not fit into `NearToken`, like a too long whole part.
+        ParseError::ExponentOverflow => DecimalNumberParsingError::LongWhole(trimmed.into()),
+        // Precision loss in exponent notation is caused by the whole number, not the fractional part alone.
+        ParseError::LongFractional if scientific => {
+            DecimalNumberParsingError::LongFractional(trimmed.into())
+        }
+        ParseError::LongWhole => {
+            let digits = whole.trim_start_matches('+').trim_start_matches('0');
+            DecimalNumberParsingError::LongWhole(digits.into())
+        }
+        ParseError::LongFractional => DecimalNumberParsingError::LongFractional(fractional.into()),
+        ParseError::MissingUnit
+        | ParseError::InvalidUnit
+        | ParseError::InvalidNumber
+        | ParseError::InvalidDigitGrouping => DecimalNumberParsingError::InvalidNumber(s.into()),
+    }
+}
+
+/// Multiplies two `u128` values into a 256-bit product represented as `(high, low)` halves.
+pub(crate) const fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const LOW_MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & LOW_MASK);
+    let (b_high, b_low) = (b >> 64, b & LOW_MASK);
+
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let high_high = a_high * b_high;
+
+    // Sum of the middle 64-bit columns; each term is below 2^64, so the sum fits into u128.
+    let middle = (low_low >> 64) + (high_low & LOW_MASK) + (low_high & LOW_MASK);
+    let low = (middle << 64) | (low_low & LOW_MASK);
+    let high = high_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Computes `a * b / denominator` using a 256-bit intermediate product, returning the quotient and the remainder.
 ///
-/// If the whole part of the number has a value more than the `u64` maximum value, it will return the error `DecimalNumberParsingError::LongWhole`.
-pub(crate) fn parse_decimal_number(
-    s: &str,
-    pref_const: u128,
-) -> Result<u128, DecimalNumberParsingError> {
-    let (int, fract) = if let Some((whole, fractional)) = s.trim().split_once('.') {
-        let int: u128 = whole
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        let mut fract: u128 = fractional
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        let len = u32::try_from(fractional.len())
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        fract = fract
-            .checked_mul(
-                pref_const
-                    .checked_div(10u128.checked_pow(len).ok_or_else(|| {
-                        DecimalNumberParsingError::LongFractional(fractional.to_owned())
-                    })?)
-                    .filter(|n| *n != 0u128)
-                    .ok_or_else(|| {
-                        DecimalNumberParsingError::LongFractional(fractional.to_owned())
-                    })?,
-            )
-            .ok_or_else(|| DecimalNumberParsingError::LongFractional(fractional.to_owned()))?;
-        (int, fract)
-    } else {
-        let int: u128 = s
-            .parse()
-            .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
-        (int, 0)
-    };
-    let result = fract
-        .checked_add(
-            int.checked_mul(pref_const)
-                .ok_or_else(|| DecimalNumberParsingError::LongWhole(int.to_string()))?,
-        )
-        .ok_or_else(|| DecimalNumberParsingError::LongWhole(int.to_string()))?;
-    Ok(result)
+/// Returns None if `denominator == 0` or the quotient does not fit into `u128`.
+pub(crate) const fn mul_div_rem(a: u128, b: u128, denominator: u128) -> Option<(u128, u128)> {
+    if denominator == 0 {
+        return None;
+    }
+    let (high, low) = widening_mul(a, b);
+    if high == 0 {
+        return Some((low / denominator, low % denominator));
+    }
+    if high >= denominator {
+        return None;
+    }
+    // Binary long division of the 256-bit product; the running remainder always stays below
+    // `denominator`, so the quotient accumulates exactly 128 bits.
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    let mut bit = 128;
+    while bit > 0 {
+        bit -= 1;
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// Fixed-capacity buffer for formatting short strings without heap allocations.
+pub(crate) struct StackBuffer<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackBuffer<N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len])
+            .expect("StackBuffer only contains complete UTF-8 strings")
+    }
+}
+
+impl<const N: usize> core::fmt::Write for StackBuffer<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(core::fmt::Error)?;
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
 }
 
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DecimalNumberParsingError {
-    InvalidNumber(String),
-    LongWhole(String),
-    LongFractional(String),
+    InvalidNumber(alloc::string::String),
+    LongWhole(alloc::string::String),
+    LongFractional(alloc::string::String),
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for DecimalNumberParsingError {}
 
-impl std::fmt::Display for DecimalNumberParsingError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for DecimalNumberParsingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             DecimalNumberParsingError::InvalidNumber(s) => {
                 write!(f, "invalid number: {}", s)
@@ -77,6 +141,20 @@ impl std::fmt::Display for DecimalNumberParsingError {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "alloc")]
+    fn parse_decimal_number(s: &str, pref_const: u128) -> Result<u128, DecimalNumberParsingError> {
+        crate::parse::parse_decimal(
+            s.as_bytes(),
+            0,
+            s.len(),
+            pref_const,
+            &crate::ParseOptions::new(),
+        )
+        .map(|(value, _)| value)
+        .map_err(|err| decimal_number_error(err, s))
+    }
+
+    #[cfg(feature = "alloc")]
     const TEST: [(u128, &str, u128); 6] = [
         (129_380_000_001_u128, "129.380000001", 10u128.pow(9)),
         (
@@ -95,6 +173,7 @@ mod tests {
     ];
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn parse_test() {
         for (expected_value, str_value, precision) in TEST {
             let parsed_value = parse_decimal_number(str_value, precision).unwrap();
@@ -103,6 +182,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn test_long_fract() {
         let data = "1.23456";
         let prefix = 10000u128;
@@ -113,6 +193,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn invalidnumber_whole() {
         let num = "1h4.7859";
         let prefix: u128 = 10000;
@@ -124,6 +205,7 @@ mod tests {
         );
     }
     #[test]
+    #[cfg(feature = "alloc")]
     fn invalidnumber_fract() {
         let num = "14.785h9";
         let prefix: u128 = 10000;
@@ -136,6 +218,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn max_long_fract() {
         let max_data = 10u128.pow(17) + 1;
         let data = "1.".to_string() + max_data.to_string().as_str();
@@ -149,6 +232,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn parse_u128_errortest() {
         let test_data = u128::MAX.to_string();
         let gas = parse_decimal_number(&test_data, 10u128.pow(9));
@@ -159,6 +243,56 @@ mod tests {
     }
 
     #[test]
+    fn mul_div_rem_test() {
+        assert_eq!(mul_div_rem(10, 3, 4), Some((7, 2)));
+        assert_eq!(mul_div_rem(10, 3, 0), None);
+        assert_eq!(
+            mul_div_rem(u128::MAX, u128::MAX, u128::MAX),
+            Some((u128::MAX, 0))
+        );
+        assert_eq!(mul_div_rem(u128::MAX, 2, 2), Some((u128::MAX, 0)));
+        assert_eq!(mul_div_rem(u128::MAX, 3, 2), None);
+        assert_eq!(
+            mul_div_rem(u128::MAX, u128::MAX - 1, u128::MAX),
+            Some((u128::MAX - 1, 0))
+        );
+        // (2^128 - 1) * 10^24 / (10^24 + 1)
+        let one_near = 10u128.pow(24);
+        let (quotient, remainder) = mul_div_rem(u128::MAX, one_near, one_near + 1).unwrap();
+        assert!(remainder <= one_near);
+        let (high, low) = widening_mul(quotient, one_near + 1);
+        let (low, carry) = low.overflowing_add(remainder);
+        assert_eq!(
+            (high + u128::from(carry), low),
+            widening_mul(u128::MAX, one_near)
+        );
+    }
+
+    #[test]
+    fn widening_mul_test() {
+        assert_eq!(widening_mul(0, u128::MAX), (0, 0));
+        assert_eq!(widening_mul(u128::MAX, 1), (0, u128::MAX));
+        assert_eq!(widening_mul(u128::MAX, 2), (1, u128::MAX - 1));
+        assert_eq!(widening_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+        assert_eq!(widening_mul(1 << 64, 1 << 64), (1, 0));
+    }
+
+    #[test]
+    fn stack_buffer() {
+        use core::fmt::Write;
+
+        let mut buf = StackBuffer::<8>::new();
+        write!(buf, "{}-{}", 12, 3).unwrap();
+        buf.write_str("b").unwrap();
+        assert_eq!(buf.as_str(), "12-3b");
+        assert!(write!(buf, "long").is_err());
+        assert_eq!(buf.as_str(), "12-3b");
+        write!(buf, "Ⓝ").unwrap();
+        assert_eq!(buf.as_str(), "12-3bⓃ");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
     fn test() {
         let data = "1.000000000000000000000000000000000000001";
         let prefix = 100u128;
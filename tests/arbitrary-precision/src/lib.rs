@@ -0,0 +1,40 @@
+//! Tests of `near_token::serde::permissive` with the `arbitrary_precision` feature of serde_json,
+//! which represents the numbers that do not fit into 64 bits as maps with a private key.
+
+#[cfg(test)]
+mod test {
+    use near_token::serde::permissive::deserialize;
+    use near_token::NearToken;
+
+    #[test]
+    fn large_numbers() {
+        let max = u128::MAX.to_string();
+        assert_eq!(
+            deserialize(serde_json::from_str::<serde_json::Value>(&max).unwrap()).unwrap(),
+            NearToken::MAX
+        );
+        assert_eq!(
+            deserialize(&mut serde_json::Deserializer::from_str(&max)).unwrap(),
+            NearToken::MAX
+        );
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        for (number, error) in [
+            (
+                "1.50",
+                "invalid amount 1.50: not a non-negative integer amount of yoctoNEAR",
+            ),
+            (
+                "3402823669209384634633746074317682114550",
+                "invalid amount 3402823669209384634633746074317682114550: \
+                 not a non-negative integer amount of yoctoNEAR",
+            ),
+        ] {
+            let value = serde_json::from_str::<serde_json::Value>(number).unwrap();
+            let err = deserialize(value).unwrap_err().to_string();
+            assert!(err.starts_with(error), "{}", err);
+        }
+    }
+}